@@ -0,0 +1,48 @@
+//! A custom natlint binary with an in-house rule, on top of the built-in rules.
+//!
+//! The rule is set in the `[plugin_rules]` section of `natlint.toml` like the built-in rules:
+//!
+//! ```toml
+//! [plugin_rules]
+//! missing_security_contact = true
+//! ```
+//!
+//! Run it with `cargo run --example custom_natlint -- run -i 'src/**/*.sol'`.
+
+use std::process::ExitCode;
+
+use natlint::prelude::*;
+
+/// This rule requires that deployable contracts have a `@custom:security-contact` comment, so
+/// that researchers know where to report vulnerabilities.
+pub struct MissingSecurityContact;
+
+impl Rule for MissingSecurityContact {
+    type Target = ContractDefinition;
+    const NAME: &'static str = "MissingSecurityContact";
+    const DESCRIPTION: &'static str = "Contracts must have a security-contact custom comment.";
+
+    fn check(
+        _: Option<&ParseItem>,
+        contract: &ContractDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let tag = CommentTag::Custom("security-contact".to_owned());
+        if !matches!(contract.ty, ContractTy::Contract(_))
+            || !comments.include_tag(tag.clone()).is_empty()
+        {
+            return None;
+        }
+
+        Some(Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            ViolationError::MissingComment(tag),
+            contract.loc,
+        ))
+    }
+}
+
+fn main() -> eyre::Result<ExitCode> {
+    natlint::cli::run(Linter::builder().rule("missing_security_contact", MissingSecurityContact))
+}
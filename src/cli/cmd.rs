@@ -1,6 +1,6 @@
 //! Defines the client interface for natlint.
 
-use clap::{command, Parser};
+use clap::Parser;
 
 /// The command line interface for natlint.
 #[derive(Clone, Debug, Parser)]
@@ -48,6 +48,10 @@ pub mod run {
         /// The verbosity of the linter.
         #[clap(short = 'v', long)]
         pub verbose: bool,
+
+        /// Apply the automatic fixes of the violations to the files.
+        #[clap(long)]
+        pub fix: bool,
     }
 }
 
@@ -1,4 +1,179 @@
 //! This module contains the command line interface and the config for the application.
 
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser;
+use itertools::Itertools;
+
+use crate::{
+    config::Config,
+    linter::LinterBuilder,
+    project::Project,
+    rules::{fix, Violation},
+};
+
+use cmd::{Commands, NatlintCli};
+use file_finder::find_matching_files;
+
 pub mod cmd;
 pub mod file_finder;
+
+/// The maximum number of times the fixes are applied with `--fix`.
+const MAX_FIX_PASSES: usize = 8;
+
+/// Runs the natlint command line interface with the rules of `linter`, which lets a custom natlint
+/// binary add its own rules on top of the built-in ones.
+///
+/// The configuration is read from the file given on the command line.
+/// # Errors
+/// Returns an error if the files cannot be found or fixed, or the configuration cannot be written.
+pub fn run(linter: LinterBuilder) -> eyre::Result<ExitCode> {
+    let cli = NatlintCli::parse();
+    match cli.command {
+        Commands::Run(args) => {
+            let config = match Config::from_file(Path::new(&args.config)) {
+                Ok(config) => config,
+                Err(e) => {
+                    println!("Error reading config file: {e}.");
+                    println!("Using default settings.");
+                    Config::default()
+                }
+            };
+            let linter = linter.config(config).build();
+
+            let files = find_matching_files(&args.root, args.include, args.exclude)?;
+            let mut project = Project::load(Path::new(&args.root), files.clone());
+            for error in project.remapping_errors() {
+                println!("Warning: {error}. The imports are resolved without it.");
+            }
+            for skipped in project.skipped_files() {
+                println!(
+                    "Error: {}: {}. The file is not linted.",
+                    relative_path(&skipped.path).display(),
+                    skipped.reason
+                );
+            }
+            for skipped in project.skipped_imports() {
+                println!("Warning: {skipped}. The checks across files ignore it.");
+            }
+
+            let mut violations = linter.lint_project(&project);
+            if args.fix {
+                // The fixes move the code, and may solve or reveal other violations, so the files
+                // are linted again until no fix applies
+                let mut fixed_count = 0;
+                for _ in 0..MAX_FIX_PASSES {
+                    let fixed = apply_fixes(&project, &violations)?;
+                    if fixed == 0 {
+                        break;
+                    }
+                    fixed_count += fixed;
+                    project = Project::load(Path::new(&args.root), files.clone());
+                    violations = linter.lint_project(&project);
+                }
+                if fixed_count > 0 {
+                    println!("Fixed {fixed_count} natspec violations.");
+                }
+            }
+
+            let file_violations: Vec<(String, Vec<(Violation, usize)>)> = violations
+                .into_iter()
+                .map(|(file, violations)| {
+                    let file = relative_path(&file).to_string_lossy().into_owned();
+                    (file, violations)
+                })
+                .sorted_by(|(file_a, _), (file_b, _)| file_a.cmp(file_b))
+                .collect::<Vec<_>>();
+
+            let violation_count = file_violations
+                .iter()
+                .map(|(_, violations)| violations.len())
+                .sum::<usize>();
+
+            for (file, violation_with_line) in &file_violations {
+                if violation_with_line.is_empty() {
+                    if args.verbose {
+                        println!("\nFile: {file}");
+                        println!("  No violations found.");
+                    }
+                    continue;
+                }
+
+                println!("\nFile: {file}");
+                for (violation, line_number) in violation_with_line {
+                    // Print violation details with converted line number
+                    println!(
+                        "  [{}] Line {}: {} {}",
+                        violation.rule_name,
+                        line_number,
+                        violation.rule_description,
+                        violation.error
+                    );
+                }
+            }
+
+            if violation_count == 0 {
+                println!("No natspec violations found.");
+                if project.skipped_files().is_empty() {
+                    Ok(ExitCode::SUCCESS)
+                } else {
+                    // The files that could not be parsed were not checked
+                    Ok(ExitCode::FAILURE)
+                }
+            } else {
+                println!(
+                    "\nFound {} natspec violations in {} files.",
+                    violation_count,
+                    file_violations.len()
+                );
+                // Return non-zero exit code if violations were found
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Commands::Init(args) => {
+            let config = linter.default_config();
+            fs::write(&args.config, toml::to_string(&config)?)?;
+            println!("Default configuration file created at {}", args.config);
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+/// Returns the path relative to the working directory, since the project paths are canonical.
+fn relative_path(path: &Path) -> PathBuf {
+    std::env::current_dir()
+        .and_then(fs::canonicalize)
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Applies the fixes of the violations to their files.
+/// Returns the number of fixed violations.
+fn apply_fixes(
+    project: &Project,
+    violations: &[(PathBuf, Vec<(Violation, usize)>)],
+) -> eyre::Result<usize> {
+    let mut fixed_count = 0;
+    for (path, file_violations) in violations {
+        let Some(file) = project.file(path) else {
+            continue;
+        };
+        let fixes = file_violations
+            .iter()
+            .filter_map(|(violation, _)| violation.fix.as_ref())
+            .collect::<Vec<_>>();
+
+        let (content, applied) = fix::apply_fixes(&file.content, &fixes);
+        let count = applied.into_iter().filter(|applied| *applied).count();
+        if count > 0 {
+            fs::write(path, content)?;
+            fixed_count += count;
+        }
+    }
+    Ok(fixed_count)
+}
@@ -0,0 +1,122 @@
+//! Per contract kind overrides of the rule tables.
+//!
+//! The `[contract]`, `[interface]`, `[library]` and `[abstract]` sections hold the same rule
+//! tables as the top-level config, e.g. `[interface.function_rules]`. Any rule left out of an
+//! override falls back to the top-level value before deserialization. Other keys, e.g.
+//! `[interface.custom_tags]`, are rejected, since only the rule tables apply per kind.
+
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{
+    comment::CommentRulesConfig, constructor::ConstructorRulesConfig,
+    contract::ContractRulesConfig, error::ErrorRulesConfig, event::EventRulesConfig,
+    function::FunctionRulesConfig, modifier::ModifierRulesConfig, r#enum::EnumRulesConfig,
+    r#struct::StructRulesConfig, r#type::TypeRulesConfig,
+    special_function::SpecialFunctionRulesConfig, variable::VariableRulesConfig, DynRule,
+};
+
+/// The config sections that override the rule tables for a contract kind.
+pub(super) const KIND_SECTIONS: [&str; 4] = ["contract", "interface", "library", "abstract"];
+
+/// The rule tables of the top-level config that a contract kind section can override.
+pub(super) const RULE_TABLES: [&str; 12] = [
+    "comment_rules",
+    "contract_rules",
+    "error_rules",
+    "function_rules",
+    "modifier_rules",
+    "constructor_rules",
+    "special_function_rules",
+    "enum_rules",
+    "struct_rules",
+    "variable_rules",
+    "event_rules",
+    "type_rules",
+];
+
+/// Rule tables applied to the items declared in a specific contract kind.
+#[derive(Serialize, Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+#[allow(missing_docs)]
+pub struct ContractKindRulesConfig {
+    #[serde(default)]
+    pub comment_rules: CommentRulesConfig,
+    #[serde(default)]
+    pub contract_rules: ContractRulesConfig,
+    #[serde(default)]
+    pub error_rules: ErrorRulesConfig,
+    #[serde(default)]
+    pub function_rules: FunctionRulesConfig,
+    #[serde(default)]
+    pub modifier_rules: ModifierRulesConfig,
+    #[serde(default)]
+    pub constructor_rules: ConstructorRulesConfig,
+    #[serde(default)]
+    pub special_function_rules: SpecialFunctionRulesConfig,
+    #[serde(default)]
+    pub enum_rules: EnumRulesConfig,
+    #[serde(default)]
+    pub struct_rules: StructRulesConfig,
+    #[serde(default)]
+    pub variable_rules: VariableRulesConfig,
+    #[serde(default)]
+    pub event_rules: EventRulesConfig,
+    #[serde(default)]
+    pub type_rules: TypeRulesConfig,
+}
+
+impl ContractKindRulesConfig {
+    /// Returns an iterator over the rules in the configuration.
+    #[must_use]
+    pub fn rules(&self) -> Vec<Box<dyn DynRule>> {
+        iter::empty()
+            .chain(self.comment_rules.rule_set())
+            .chain(self.contract_rules.rule_set())
+            .chain(self.error_rules.rule_set())
+            .chain(self.function_rules.rule_set())
+            .chain(self.modifier_rules.rule_set())
+            .chain(self.constructor_rules.rule_set())
+            .chain(self.special_function_rules.rule_set())
+            .chain(self.enum_rules.rule_set())
+            .chain(self.struct_rules.rule_set())
+            .chain(self.variable_rules.rule_set())
+            .chain(self.event_rules.rule_set())
+            .chain(self.type_rules.rule_set())
+            .collect()
+    }
+}
+
+/// Copies the top-level rule tables into every contract kind section, without overwriting the
+/// rules set in the section itself. The other top-level sections, e.g. `custom_tags`, apply to
+/// every kind already and are not copied.
+pub(super) fn inherit_base_tables(config: &mut toml::Table) {
+    let base = config
+        .iter()
+        .filter(|(key, value)| RULE_TABLES.contains(&key.as_str()) && value.is_table())
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+
+    for section in KIND_SECTIONS {
+        let Some(toml::Value::Table(kind)) = config.get_mut(section) else {
+            continue;
+        };
+
+        for (key, value) in &base {
+            match (kind.get_mut(key), value) {
+                (Some(toml::Value::Table(overrides)), toml::Value::Table(base_rules)) => {
+                    for (rule, enabled) in base_rules {
+                        overrides
+                            .entry(rule.clone())
+                            .or_insert_with(|| enabled.clone());
+                    }
+                }
+                (None, _) => {
+                    kind.insert(key.clone(), value.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+}
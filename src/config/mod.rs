@@ -2,18 +2,39 @@
 //!
 //! This module provides configuration for natlint rules, including loading default
 //! rules and applying them to parsed Solidity items.
-use std::{fs, iter, path::Path};
+use std::{collections::BTreeSet, fs, iter, path::Path};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use toml;
 
+use crate::linter::LintOptions;
 use crate::rules::{
-    contract::ContractRulesConfig, error::ErrorRulesConfig, event::EventRulesConfig,
-    function::FunctionRulesConfig, r#enum::EnumRulesConfig, r#struct::StructRulesConfig,
-    variable::VariableRulesConfig, DynRule,
+    comment::CommentRulesConfig,
+    constructor::ConstructorRulesConfig,
+    contract::ContractRulesConfig,
+    custom_rule::CustomRulesConfig,
+    custom_tag::{CustomTagsConfig, DisallowedCustomTag, InvalidCustomTagValue, MissingCustomTag},
+    error::ErrorRulesConfig,
+    event::EventRulesConfig,
+    function::FunctionRulesConfig,
+    modifier::ModifierRulesConfig,
+    r#enum::EnumRulesConfig,
+    r#struct::StructRulesConfig,
+    r#type::TypeRulesConfig,
+    scope::{ContractKind, Scope, ScopedRule},
+    special_function::SpecialFunctionRulesConfig,
+    tag_pattern::{TagPattern, TagPatternConfig},
+    variable::VariableRulesConfig,
+    DynRule,
 };
 
+mod kind;
+mod plugin;
+
+pub use kind::ContractKindRulesConfig;
+pub use plugin::PluginRulesConfig;
+
 /// Errors that can occur during configuration processing.
 #[derive(Error, Debug)]
 pub enum ConfigError {
@@ -23,12 +44,41 @@ pub enum ConfigError {
     /// Error parsing the TOML configuration.
     #[error("Failed to parse TOML config: {0}")]
     TomlParse(#[from] toml::de::Error),
+    /// A `filters` entry that names no rule of its table, e.g. `missing_param`.
+    #[error("Unknown rule `{rule}` in `[{table}.filters]`")]
+    UnknownFilter {
+        /// The rule table of the filter, e.g. `interface.function_rules`.
+        table: String,
+        /// The key of the filter.
+        rule: String,
+    },
+    /// A `[[rules.custom]]` entry that can never be satisfied or is reported as another rule.
+    #[error("Invalid custom rule `{name}`: {reason}")]
+    InvalidCustomRule {
+        /// The name of the entry.
+        name: String,
+        /// Why the entry is invalid.
+        reason: String,
+    },
 }
 
 /// Configuration for natlint rules
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 #[allow(missing_docs)]
 pub struct Config {
+    /// Also check the docs items inherit through `@inheritdoc`, see [`LintOptions`].
+    #[serde(default)]
+    pub effective_docs: bool,
+    #[serde(default)]
+    pub custom_tags: CustomTagsConfig,
+    /// The declarative rules of the `[[rules.custom]]` section.
+    #[serde(default, rename = "rules")]
+    pub custom_rules: CustomRulesConfig,
+    /// The declarative pattern rules of the `[[patterns]]` section.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<TagPatternConfig>,
+    #[serde(default)]
+    pub comment_rules: CommentRulesConfig,
     #[serde(default)]
     pub contract_rules: ContractRulesConfig,
     #[serde(default)]
@@ -36,6 +86,12 @@ pub struct Config {
     #[serde(default)]
     pub function_rules: FunctionRulesConfig,
     #[serde(default)]
+    pub modifier_rules: ModifierRulesConfig,
+    #[serde(default)]
+    pub constructor_rules: ConstructorRulesConfig,
+    #[serde(default)]
+    pub special_function_rules: SpecialFunctionRulesConfig,
+    #[serde(default)]
     pub enum_rules: EnumRulesConfig,
     #[serde(default)]
     pub struct_rules: StructRulesConfig,
@@ -43,20 +99,97 @@ pub struct Config {
     pub variable_rules: VariableRulesConfig,
     #[serde(default)]
     pub event_rules: EventRulesConfig,
+    #[serde(default)]
+    pub type_rules: TypeRulesConfig,
+    /// The rules registered by plugins, see [`LinterBuilder`](crate::linter::LinterBuilder).
+    #[serde(default, skip_serializing_if = "PluginRulesConfig::is_empty")]
+    pub plugin_rules: PluginRulesConfig,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<ContractKindRulesConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interface: Option<ContractKindRulesConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub library: Option<ContractKindRulesConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#abstract: Option<ContractKindRulesConfig>,
 }
 
 impl Config {
+    /// Returns the options to lint a project with.
+    #[must_use]
+    pub const fn lint_options(&self) -> LintOptions {
+        LintOptions {
+            effective_docs: self.effective_docs,
+        }
+    }
+
     /// Returns an iterator over the rules in the configuration.
+    ///
+    /// The top-level rule tables apply to every item that is not declared in a contract kind
+    /// with its own section, which applies its tables instead.
     #[must_use]
     pub fn rules(&self) -> Vec<Box<dyn DynRule>> {
+        let overrides = self.kind_overrides();
+        let base_scope = Scope {
+            excluded_kinds: overrides.iter().map(|(kind, _)| *kind).collect(),
+            ..Scope::default()
+        };
+
+        self.base_rules()
+            .into_iter()
+            .map(|rule| scoped(rule, base_scope.clone()))
+            .chain(overrides.into_iter().flat_map(|(kind, config)| {
+                let scope = Scope {
+                    kinds: Some(vec![kind]),
+                    ..Scope::default()
+                };
+                config
+                    .rules()
+                    .into_iter()
+                    .map(move |rule| scoped(rule, scope.clone()))
+            }))
+            // The custom tag policy, the patterns and the custom rules apply to every contract
+            // kind, unless restricted by the rule itself
+            .chain(self.custom_tags.rule_set())
+            .chain(self.pattern_rules())
+            .chain(self.custom_rules.rule_set())
+            .collect()
+    }
+
+    /// Returns the rule checking the `[[patterns]]` entries, if any.
+    fn pattern_rules(&self) -> Option<Box<dyn DynRule>> {
+        (!self.patterns.is_empty())
+            .then(|| Box::new(TagPattern::new(&self.patterns)) as Box<dyn DynRule>)
+    }
+
+    /// Returns the contract kinds with their own rule tables.
+    fn kind_overrides(&self) -> Vec<(ContractKind, &ContractKindRulesConfig)> {
+        [
+            (ContractKind::Contract, &self.contract),
+            (ContractKind::Interface, &self.interface),
+            (ContractKind::Library, &self.library),
+            (ContractKind::Abstract, &self.r#abstract),
+        ]
+        .into_iter()
+        .filter_map(|(kind, config)| Some((kind, config.as_ref()?)))
+        .collect()
+    }
+
+    /// Returns the rules of the top-level rule tables.
+    fn base_rules(&self) -> Vec<Box<dyn DynRule>> {
         iter::empty()
+            .chain(self.comment_rules.rule_set())
             .chain(self.contract_rules.rule_set())
             .chain(self.error_rules.rule_set())
             .chain(self.function_rules.rule_set())
+            .chain(self.modifier_rules.rule_set())
+            .chain(self.constructor_rules.rule_set())
+            .chain(self.special_function_rules.rule_set())
             .chain(self.enum_rules.rule_set())
             .chain(self.struct_rules.rule_set())
             .chain(self.variable_rules.rule_set())
             .chain(self.event_rules.rule_set())
+            .chain(self.type_rules.rule_set())
             .collect()
     }
 
@@ -65,6 +198,109 @@ impl Config {
     /// Returns `ConfigError` if the file cannot be read or parsed.
     pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
         let content = fs::read_to_string(path)?;
-        Ok(toml::from_str(&content)?)
+        Self::from_table(toml::from_str(&content)?)
+    }
+
+    /// Load configuration from a TOML table, filling the contract kind sections with the
+    /// top-level rule tables.
+    fn from_table(mut table: toml::Table) -> Result<Self, ConfigError> {
+        kind::inherit_base_tables(&mut table);
+        let config: Self = table.try_into()?;
+        config.check_filters()?;
+        config.check_custom_rules()?;
+        Ok(config)
+    }
+
+    /// Checks that the `filters` of the rule tables only name rules of their table, since a typo
+    /// would leave the rule unfiltered. The `[plugin_rules]` filters are left out, since plugins
+    /// register their rules after the configuration is loaded.
+    fn check_filters(&self) -> Result<(), ConfigError> {
+        let config = toml::Table::try_from(self).expect("the config serializes to TOML");
+        let kinds = kind::KIND_SECTIONS
+            .iter()
+            .filter_map(|kind| Some((format!("{kind}."), config.get(*kind)?.as_table()?)));
+
+        for (prefix, tables) in iter::once((String::new(), &config)).chain(kinds) {
+            for name in kind::RULE_TABLES {
+                let Some(table) = tables.get(name).and_then(toml::Value::as_table) else {
+                    continue;
+                };
+                let Some(filters) = table.get("filters").and_then(toml::Value::as_table) else {
+                    continue;
+                };
+                // The rules of a table are its boolean keys, e.g. `missing_params = true`
+                if let Some(rule) = filters
+                    .keys()
+                    .find(|rule| !table.get(*rule).is_some_and(toml::Value::is_bool))
+                {
+                    return Err(ConfigError::UnknownFilter {
+                        table: format!("{prefix}{name}"),
+                        rule: rule.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the `[[rules.custom]]` entries set a count of comments that can be met, and
+    /// don't take the name of a built-in rule, whose violations and disable comments they would be
+    /// mistaken for.
+    fn check_custom_rules(&self) -> Result<(), ConfigError> {
+        if self.custom_rules.custom.is_empty() {
+            return Ok(());
+        }
+
+        let builtin = Self::builtin_rule_names();
+        for rule in &self.custom_rules.custom {
+            let reason = match (rule.at_least, rule.at_most) {
+                (None, None) => "it sets neither `at_least` nor `at_most`",
+                (Some(min), Some(max)) if min > max => "`at_least` is greater than `at_most`",
+                _ if builtin.contains(rule.name.as_str()) => "a built-in rule has the same name",
+                _ => continue,
+            };
+            return Err(ConfigError::InvalidCustomRule {
+                name: rule.name.clone(),
+                reason: reason.to_owned(),
+            });
+        }
+        Ok(())
     }
+
+    /// Returns the names of all the built-in rules, including the disabled ones.
+    fn builtin_rule_names() -> BTreeSet<&'static str> {
+        // Every rule of the rule tables is a boolean key, so enabling them all builds every rule
+        let mut table =
+            toml::Table::try_from(Self::default()).expect("the config serializes to TOML");
+        for name in kind::RULE_TABLES {
+            if let Some(rules) = table.get_mut(name).and_then(toml::Value::as_table_mut) {
+                for (_, value) in rules.iter_mut().filter(|(_, value)| value.is_bool()) {
+                    *value = toml::Value::Boolean(true);
+                }
+            }
+        }
+        let config: Self = table.try_into().expect("the enabled rules deserialize");
+
+        config
+            .base_rules()
+            .iter()
+            .map(|rule| rule.name())
+            .chain([
+                DisallowedCustomTag::NAME,
+                MissingCustomTag::NAME,
+                InvalidCustomTagValue::NAME,
+                TagPattern::NAME,
+            ])
+            .collect()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::from_table(toml::Table::new()).expect("the default config is valid")
+    }
+}
+
+fn scoped(rule: Box<dyn DynRule>, scope: Scope) -> Box<dyn DynRule> {
+    Box::new(ScopedRule::new(rule, scope))
 }
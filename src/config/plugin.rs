@@ -0,0 +1,40 @@
+//! Configuration of the rules registered by plugins, see [`LinterBuilder`].
+//!
+//! The `[plugin_rules]` section works like the built-in rule tables, keyed by the config key each
+//! rule is registered with:
+//!
+//! ```toml
+//! [plugin_rules]
+//! missing_security_note = true
+//!
+//! [plugin_rules.filters]
+//! missing_security_note = { visibility = ["external", "public"] }
+//! ```
+//!
+//! [`LinterBuilder`]: crate::linter::LinterBuilder
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::scope::RuleFilter;
+
+/// Configuration for the rules registered by plugins.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluginRulesConfig {
+    /// Whether each rule is enabled, by config key. Rules left out use the default they were
+    /// registered with.
+    #[serde(flatten)]
+    pub enabled: BTreeMap<String, bool>,
+    /// The per-rule filters, by config key.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub filters: BTreeMap<String, RuleFilter>,
+}
+
+impl PluginRulesConfig {
+    /// Returns `true` if no plugin rule is configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.enabled.is_empty() && self.filters.is_empty()
+    }
+}
@@ -5,4 +5,6 @@ pub mod cli;
 pub mod config;
 pub mod linter;
 pub mod parser;
+pub mod prelude;
+pub mod project;
 pub mod rules;
@@ -0,0 +1,149 @@
+//! A linter with the rules of the configuration and the rules registered by plugins.
+
+use std::path::PathBuf;
+
+use crate::{
+    config::Config,
+    project::Project,
+    rules::{
+        scope::{Scope, ScopedRule},
+        DynRule, Violation,
+    },
+};
+
+use super::LintOptions;
+
+/// A rule registered by a plugin, under its config key.
+struct PluginRule {
+    key: String,
+    enabled: bool,
+    rule: Box<dyn DynRule>,
+}
+
+/// Builds a [`Linter`] from a configuration and extra rules, e.g. the proprietary rules of an
+/// organization shipped in their own natlint binary.
+///
+/// ```no_run
+/// use natlint::prelude::*;
+///
+/// # struct MissingSecurityNote;
+/// # impl Rule for MissingSecurityNote {
+/// #     type Target = FunctionDefinition;
+/// #     const NAME: &'static str = "MissingSecurityNote";
+/// #     const DESCRIPTION: &'static str = "";
+/// #     fn check(_: Option<&ParseItem>, _: &FunctionDefinition, _: &CommentsRef) -> Option<Violation> {
+/// #         None
+/// #     }
+/// # }
+/// let linter = Linter::builder()
+///     .config(Config::from_file("natlint.toml".as_ref())?)
+///     .rule("missing_security_note", MissingSecurityNote)
+///     .build();
+/// # Ok::<(), natlint::config::ConfigError>(())
+/// ```
+#[derive(Default)]
+pub struct LinterBuilder {
+    config: Config,
+    plugins: Vec<PluginRule>,
+}
+
+impl LinterBuilder {
+    /// Sets the configuration, the default configuration otherwise.
+    #[must_use]
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Registers a rule under a config key, e.g. `missing_security_note` to set it in the
+    /// `[plugin_rules]` section. The rule is enabled unless disabled in the configuration.
+    #[must_use]
+    pub fn rule(self, key: impl Into<String>, rule: impl DynRule + 'static) -> Self {
+        self.plugin(key.into(), true, Box::new(rule))
+    }
+
+    /// Registers a rule under a config key, which is disabled unless enabled in the
+    /// configuration.
+    #[must_use]
+    pub fn disabled_rule(self, key: impl Into<String>, rule: impl DynRule + 'static) -> Self {
+        self.plugin(key.into(), false, Box::new(rule))
+    }
+
+    /// Registers an already boxed rule under a config key, enabled by default or not.
+    #[must_use]
+    pub fn plugin(mut self, key: String, enabled: bool, rule: Box<dyn DynRule>) -> Self {
+        self.plugins.push(PluginRule { key, enabled, rule });
+        self
+    }
+
+    /// Returns the default configuration, with the registered rules set to their default in the
+    /// `[plugin_rules]` section, e.g. for `natlint init`.
+    #[must_use]
+    pub fn default_config(&self) -> Config {
+        let mut config = Config::default();
+        config.plugin_rules.enabled.extend(
+            self.plugins
+                .iter()
+                .map(|plugin| (plugin.key.clone(), plugin.enabled)),
+        );
+        config
+    }
+
+    /// Builds the linter, with the rules enabled in the configuration.
+    #[must_use]
+    pub fn build(self) -> Linter {
+        let plugin_rules = &self.config.plugin_rules;
+        let plugins = self.plugins.into_iter().filter_map(|plugin| {
+            if !plugin_rules
+                .enabled
+                .get(&plugin.key)
+                .copied()
+                .unwrap_or(plugin.enabled)
+            {
+                return None;
+            }
+            Some(match plugin_rules.filters.get(&plugin.key) {
+                Some(filter) => Box::new(ScopedRule::new(plugin.rule, Scope::from(filter))),
+                None => plugin.rule,
+            })
+        });
+
+        Linter {
+            rule_set: self.config.rules().into_iter().chain(plugins).collect(),
+            options: self.config.lint_options(),
+        }
+    }
+}
+
+/// Lints Solidity sources against a set of rules, see [`LinterBuilder`].
+pub struct Linter {
+    rule_set: Vec<Box<dyn DynRule>>,
+    options: LintOptions,
+}
+
+impl Linter {
+    /// Returns a builder for a linter.
+    #[must_use]
+    pub fn builder() -> LinterBuilder {
+        LinterBuilder::default()
+    }
+
+    /// Returns the enabled rules.
+    #[must_use]
+    pub fn rules(&self) -> &[Box<dyn DynRule>] {
+        &self.rule_set
+    }
+
+    /// Lints a string (e.g. a file), see [`lint`](super::lint).
+    /// # Errors
+    /// Returns an error if the content cannot be parsed or checked for whatever reason
+    pub fn lint(&self, content: &str) -> eyre::Result<Vec<(Violation, usize)>> {
+        super::lint(content, &self.rule_set)
+    }
+
+    /// Lints the target files of a project, see [`lint_project`](super::lint_project).
+    #[must_use]
+    pub fn lint_project(&self, project: &Project) -> Vec<(PathBuf, Vec<(Violation, usize)>)> {
+        super::lint_project(project, &self.rule_set, self.options)
+    }
+}
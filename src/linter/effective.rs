@@ -0,0 +1,152 @@
+//! Checks the effective docs of items with an `@inheritdoc` comment.
+//!
+//! The effective docs are the item's own comments merged with the comments of the member they
+//! inherit from, following `@inheritdoc` chains across the project. Violations of the merged docs
+//! are reported at the declaration the inherited docs originate from, unless they are caused by
+//! the item's own comments, or already reported on the item without the inherited docs.
+
+use std::path::{Path, PathBuf};
+
+use alloy_primitives::map::HashMap;
+use solang_parser::pt::Loc;
+
+use crate::{
+    parser::{CommentTag, Comments, CommentsRef, ParseItem},
+    project::{signature::signature, ContractRef, Project, SourceFile},
+    rules::{context::RuleContext, violation_error::ViolationError, DynRule, Violation},
+};
+
+/// The maximum number of `@inheritdoc` comments followed for a single item.
+const MAX_DEPTH: usize = 16;
+
+/// The merged docs of an item, and the declaration they originate from.
+struct EffectiveDocs<'a> {
+    comments: Comments,
+    path: &'a Path,
+    loc: Loc,
+}
+
+/// Checks the effective docs of the members of the contracts in `file`.
+/// Returns the violations along with the path of the file they are reported in.
+pub(super) fn lint_file(
+    project: &Project,
+    file: &SourceFile,
+    rule_set: &Vec<Box<dyn DynRule>>,
+) -> Vec<(PathBuf, Violation)> {
+    let ctx = RuleContext::from_file(file).with_project(project, &file.path);
+
+    let mut violations = vec![];
+    for item in &file.items {
+        let Some(contract) = item
+            .as_contract()
+            .and_then(|contract| contract.name.as_ref())
+            .and_then(|name| project.resolve_contract(&file.path, &name.name))
+        else {
+            continue;
+        };
+        let ctx = RuleContext {
+            parent: Some(item),
+            ..ctx
+        };
+
+        for child in &item.children {
+            // Items whose inherited docs cannot be resolved are left to the other rules
+            let Some(docs) = effective_docs(project, contract, child, 0) else {
+                continue;
+            };
+
+            let own = CommentsRef::from(&child.comments);
+            let direct = super::check_node(child, &ctx, &own, rule_set);
+
+            let comments = CommentsRef::from(&docs.comments);
+            violations.extend(
+                super::check_node(child, &ctx, &comments, rule_set)
+                    .into_iter()
+                    .filter(|violation| !is_inheritdoc_violation(&violation.error))
+                    .filter(|violation| {
+                        !direct.iter().any(|reported| {
+                            reported.rule_name == violation.rule_name
+                                && reported.error == violation.error
+                        })
+                    })
+                    .map(|violation| {
+                        // A violation at one of the item's own tags is not inherited
+                        if is_own_comment(&child.comments, violation.loc) {
+                            return (file.path.clone(), violation);
+                        }
+                        (
+                            docs.path.to_path_buf(),
+                            Violation::new(
+                                violation.rule_name,
+                                violation.rule_description,
+                                violation.error,
+                                docs.loc,
+                            ),
+                        )
+                    }),
+            );
+        }
+    }
+
+    violations
+}
+
+/// Returns the effective docs of `item`, a member of `contract`, or `None` if it has no
+/// `@inheritdoc` comment or the inherited member cannot be resolved.
+fn effective_docs<'a>(
+    project: &'a Project,
+    contract: ContractRef<'a>,
+    item: &ParseItem,
+    depth: usize,
+) -> Option<EffectiveDocs<'a>> {
+    let base = item.comments.find_inheritdoc_base()?;
+    if depth >= MAX_DEPTH {
+        return None;
+    }
+
+    let signature = signature(&item.source)?;
+    let (base_contract, base_item) = project.inheritdoc_target(&contract, base, &signature)?;
+    let inherited = if base_item.comments.find_inheritdoc_base().is_some() {
+        effective_docs(project, base_contract, base_item, depth + 1)?
+    } else {
+        EffectiveDocs {
+            comments: base_item.comments.clone(),
+            path: base_contract.path,
+            loc: base_item.source.loc(),
+        }
+    };
+
+    let ident = item.source.ident();
+    let mut comments = item.comments.merge_inheritdoc(
+        &ident,
+        Some(HashMap::from_iter([(
+            format!("{base}.{ident}"),
+            inherited.comments,
+        )])),
+    );
+    comments.retain(|comment| comment.tag != CommentTag::Inheritdoc);
+
+    Some(EffectiveDocs {
+        comments,
+        ..inherited
+    })
+}
+
+/// Returns `true` if `loc` is the location of one of the comments, i.e. a violation at `loc` is
+/// caused by a tag written on the item rather than inherited.
+fn is_own_comment(comments: &Comments, loc: Loc) -> bool {
+    loc != Loc::Implicit && comments.iter().any(|comment| comment.loc == loc)
+}
+
+/// Returns `true` for the violations about the `@inheritdoc` comment itself, which no longer
+/// applies once the docs are merged.
+const fn is_inheritdoc_violation(error: &ViolationError) -> bool {
+    matches!(
+        error,
+        ViolationError::MissingComment(CommentTag::Inheritdoc)
+            | ViolationError::TooManyComments(CommentTag::Inheritdoc)
+            | ViolationError::CommentNotAllowed(CommentTag::Inheritdoc)
+            | ViolationError::OnlyInheritdoc
+            | ViolationError::InvalidInheritdoc(_)
+    )
+}
@@ -1,19 +1,29 @@
 //! The linter implementation
 
+mod builder;
 mod disable;
+mod effective;
 
-use std::any::{Any, TypeId};
+pub use builder::{Linter, LinterBuilder};
 
-use line_col::LineColLookup;
+use std::{any::TypeId, collections::BTreeMap, path::PathBuf};
 
-use crate::parser::ParseSource;
+use line_col::LineColLookup;
 
 use crate::{
-    parser::{CommentsRef, ParseItem, Parser},
-    rules::{DynRule, Violation},
+    parser::{CommentsRef, ParseItem},
+    project::{Project, SourceFile},
+    rules::{context::RuleContext, DynRule, Violation},
 };
-use forge_fmt::Visitable;
-use solang_parser::parse;
+
+/// Options for linting a project.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LintOptions {
+    /// Also check the effective docs of items with an `@inheritdoc` comment, i.e. their own docs
+    /// merged with the docs of the member they inherit from. Incomplete inherited docs are
+    /// reported at the declaration they originate from.
+    pub effective_docs: bool,
+}
 
 /// Lints a string (e.g. a file) against a set of rules
 /// # Errors
@@ -22,67 +32,160 @@ pub fn lint(
     content: &str,
     rule_set: &Vec<Box<dyn DynRule>>,
 ) -> eyre::Result<Vec<(Violation, usize)>> {
-    let disable_directives = disable::disable_next_line_directives(content);
+    let file = SourceFile::parse(PathBuf::new(), content.to_owned())?;
+    Ok(with_lines(&file, lint_file(&file, None, rule_set)))
+}
 
-    let line_lookup = LineColLookup::new(content);
-    let (mut source_unit, comments) =
-        parse(content, 0).map_err(|e| eyre::eyre!("Failed to parse content: {:?}", e))?;
+/// Lints the target files of a project against a set of rules.
+/// Unlike [`lint`], the rules can look up the other files of the project, e.g. to resolve the
+/// bases of a contract.
+///
+/// With [`LintOptions::effective_docs`], violations may also be reported in files that are not
+/// targets, where the inherited docs are declared.
+#[must_use]
+pub fn lint_project(
+    project: &Project,
+    rule_set: &Vec<Box<dyn DynRule>>,
+    options: LintOptions,
+) -> Vec<(PathBuf, Vec<(Violation, usize)>)> {
+    let mut violations = project
+        .targets()
+        .map(|file| (file.path.clone(), lint_file(file, Some(project), rule_set)))
+        .collect::<BTreeMap<_, _>>();
 
-    let mut parser = Parser::new(comments, content.to_owned());
-    source_unit
-        .visit(&mut parser)
-        .map_err(|e| eyre::eyre!("Failed to visit: {:?}", e))?;
+    if options.effective_docs {
+        for file in project.targets() {
+            for (path, violation) in effective::lint_file(project, file, rule_set) {
+                let file_violations = violations.entry(path).or_default();
+                if !file_violations.contains(&violation) {
+                    file_violations.push(violation);
+                }
+            }
+        }
+    }
 
-    Ok(parser
-        .items()
+    violations
         .into_iter()
-        .flat_map(|item| process_item(&item, None, rule_set, &line_lookup))
-        .filter(|(violation, line)| !disable_directives.is_disabled(*line, violation.rule_name))
-        .collect::<Vec<_>>())
+        .filter_map(|(path, violations)| {
+            let file = project.file(&path)?;
+            Some((path, with_lines(file, violations)))
+        })
+        .collect()
 }
 
-fn process_item(
-    item: &ParseItem,
-    parent: Option<&ParseItem>,
+fn lint_file(
+    file: &SourceFile,
+    project: Option<&Project>,
     rule_set: &Vec<Box<dyn DynRule>>,
-    line_lookup: &LineColLookup,
-) -> Vec<(Violation, usize)> {
-    let comments_ref = CommentsRef::from(&item.comments);
+) -> Vec<Violation> {
+    let mut ctx = RuleContext::from_file(file);
+    if let Some(project) = project {
+        ctx = ctx.with_project(project, &file.path);
+    }
 
-    // Get the inner AST node and its TypeId
-    let (source_item, source_type_id): (&dyn Any, TypeId) = match &item.source {
-        ParseSource::Contract(inner) => (
-            inner.as_ref(),
-            TypeId::of::<solang_parser::pt::ContractDefinition>(),
-        ),
-        ParseSource::Function(inner) => {
-            (inner, TypeId::of::<solang_parser::pt::FunctionDefinition>())
-        }
-        ParseSource::Variable(inner) => {
-            (inner, TypeId::of::<solang_parser::pt::VariableDefinition>())
-        }
-        ParseSource::Event(inner) => (inner, TypeId::of::<solang_parser::pt::EventDefinition>()),
-        ParseSource::Error(inner) => (inner, TypeId::of::<solang_parser::pt::ErrorDefinition>()),
-        ParseSource::Struct(inner) => (inner, TypeId::of::<solang_parser::pt::StructDefinition>()),
-        ParseSource::Enum(inner) => (inner, TypeId::of::<solang_parser::pt::EnumDefinition>()),
-        ParseSource::Type(inner) => (inner, TypeId::of::<solang_parser::pt::TypeDefinition>()),
-    };
-
-    rule_set
+    file.items
         .iter()
-        // Filter rules based on the TypeId of the inner AST node
-        .filter(|rule| rule.target_type_id() == source_type_id)
-        // Pass the inner AST node (&dyn Any) to check_dyn
-        .filter_map(|rule| rule.check_dyn(parent, source_item, &comments_ref))
+        .flat_map(|item| process_item(item, &ctx, rule_set))
+        .chain(
+            rule_set
+                .iter()
+                .filter(|rule| rule.target_type_id() == TypeId::of::<SourceFile>())
+                .flat_map(|rule| rule.check_file(&ctx, file)),
+        )
+        .collect()
+}
+
+/// Converts the violations of a file to their line numbers, dropping the disabled ones.
+fn with_lines(file: &SourceFile, violations: Vec<Violation>) -> Vec<(Violation, usize)> {
+    let disable_directives = disable::disable_next_line_directives(&file.content);
+    let line_lookup = LineColLookup::new(&file.content);
+
+    violations
+        .into_iter()
         .map(|violation| {
             // Convert the line number to the original source line
             let (line, _) = line_lookup.get(violation.loc.start());
             (violation, line)
         })
+        .filter(|(violation, line)| {
+            // A violation reported at a doc comment can also be disabled above its declaration
+            let declaration = documented_item(&file.items, violation.loc.start())
+                .map(|item| line_lookup.get(item.source.loc().start()).0);
+            [Some(*line), declaration]
+                .into_iter()
+                .flatten()
+                .all(|line| !disable_directives.is_disabled(line, violation.rule_name))
+        })
+        .collect()
+}
+
+/// Returns the item documented by the doc comment at the given offset, if any.
+fn documented_item(items: &[ParseItem], offset: usize) -> Option<&ParseItem> {
+    items.iter().find_map(|item| {
+        let documents = item
+            .comments
+            .source_comments()
+            .iter()
+            .any(|source| (source.loc.start()..source.loc.end()).contains(&offset));
+        if documents {
+            return Some(item);
+        }
+        documented_item(&item.children, offset)
+    })
+}
+
+fn process_item(
+    item: &ParseItem,
+    ctx: &RuleContext,
+    rule_set: &Vec<Box<dyn DynRule>>,
+) -> Vec<Violation> {
+    check_item(item, ctx, rule_set)
+        .into_iter()
+        .chain(item.children.iter().flat_map(|child| {
+            let ctx = RuleContext {
+                parent: Some(item),
+                ..*ctx
+            };
+            process_item(child, &ctx, rule_set)
+        }))
+        .collect()
+}
+
+/// Checks a single item, without its children, against the rules.
+fn check_item(
+    item: &ParseItem,
+    ctx: &RuleContext,
+    rule_set: &Vec<Box<dyn DynRule>>,
+) -> Vec<Violation> {
+    let comments = CommentsRef::from(&item.comments);
+
+    check_node(item, ctx, &comments, rule_set)
+        .into_iter()
         .chain(
-            item.children
+            rule_set
                 .iter()
-                .flat_map(|child| process_item(child, Some(item), rule_set, line_lookup)),
+                // Rules targeting the whole item, e.g. to check its raw comments
+                .filter(|rule| rule.target_type_id() == TypeId::of::<ParseItem>())
+                .flat_map(|rule| rule.check_all(ctx, item, &comments)),
         )
-        .collect::<Vec<_>>()
+        .collect()
+}
+
+/// Checks the inner AST node of an item against the rules with the given comments.
+fn check_node(
+    item: &ParseItem,
+    ctx: &RuleContext,
+    comments: &CommentsRef,
+    rule_set: &Vec<Box<dyn DynRule>>,
+) -> Vec<Violation> {
+    // Get the inner AST node and its TypeId
+    let (source_item, source_type_id) = item.source.node();
+
+    rule_set
+        .iter()
+        // Filter rules based on the TypeId of the inner AST node
+        .filter(|rule| rule.target_type_id() == source_type_id)
+        // Pass the inner AST node (&dyn Any) to check_all
+        .flat_map(|rule| rule.check_all(ctx, source_item, comments))
+        .collect()
 }
@@ -1,6 +1,6 @@
 use alloy_primitives::map::HashMap;
 use derive_more::{derive::Display, Deref, DerefMut};
-use solang_parser::doccomment::DocCommentTag;
+use solang_parser::{doccomment::DocCommentTag, pt::Loc};
 
 /// The natspec comment tag explaining the purpose of the comment.
 /// See: <https://docs.soliditylang.org/en/v0.8.17/natspec-format.html#tags>.
@@ -25,7 +25,7 @@ pub enum CommentTag {
 }
 
 impl CommentTag {
-    fn from_str(s: &str) -> Option<Self> {
+    pub(crate) fn from_str(s: &str) -> Option<Self> {
         let trimmed = s.trim();
         let tag = match trimmed {
             "title" => Self::Title,
@@ -67,21 +67,40 @@ pub struct Comment {
     pub tag: CommentTag,
     /// The doc comment value.
     pub value: String,
+    /// The location of the comment, from the `@` to the end of the first line of the value, or
+    /// [Loc::Implicit] if it is unknown.
+    pub loc: Loc,
 }
 
 impl Comment {
     /// Create new instance of [Comment].
     pub fn new(tag: CommentTag, value: String) -> Self {
-        Self { tag, value }
+        Self {
+            tag,
+            value,
+            loc: Loc::Implicit,
+        }
+    }
+
+    /// Set the location of the comment.
+    pub fn with_loc(mut self, loc: Loc) -> Self {
+        self.loc = loc;
+        self
     }
 
     /// Create new instance of [Comment] from [DocCommentTag]
     /// if it has a valid natspec tag.
     pub fn from_doc_comment(value: DocCommentTag) -> Option<Self> {
-        CommentTag::from_str(&value.tag).map(|tag| Self {
-            tag,
-            value: value.value,
-        })
+        let loc = doc_comment_loc(&value);
+        CommentTag::from_str(&value.tag).map(|tag| Self::new(tag, value.value).with_loc(loc))
+    }
+
+    /// Returns the location of the comment, or `fallback` if it is unknown.
+    pub fn loc_or(&self, fallback: Loc) -> Loc {
+        match self.loc {
+            Loc::Implicit => fallback,
+            loc => loc,
+        }
     }
 
     /// Split the comment at first word.
@@ -111,9 +130,48 @@ impl Comment {
     }
 }
 
+/// A natspec tag that is not recognized, along with its location.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidTag {
+    /// The tag as written, without the `@`.
+    pub tag: String,
+    /// The value of the tag.
+    pub value: String,
+    /// The location of the tag, including the `@`.
+    pub loc: Loc,
+}
+
+/// The style of a doc comment in the source.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum DocStyle {
+    /// A `///` line comment.
+    #[display("`///`")]
+    Line,
+    /// A `/** */` block comment.
+    #[display("`/** */`")]
+    Block,
+}
+
+/// A doc comment as written in the source, which may hold several [Comment]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceComment {
+    /// The style of the doc comment.
+    pub style: DocStyle,
+    /// The location of the doc comment, from the `///` or `/**` to the end of the comment.
+    pub loc: Loc,
+}
+
 /// The collection of natspec [Comment] items.
 #[derive(Clone, Debug, Default, PartialEq, Deref, DerefMut)]
-pub struct Comments(Vec<Comment>);
+pub struct Comments {
+    #[deref]
+    #[deref_mut]
+    comments: Vec<Comment>,
+    /// The tags that could not be parsed into a [Comment].
+    invalid: Vec<InvalidTag>,
+    /// The doc comments the natspec comments were parsed from.
+    sources: Vec<SourceComment>,
+}
 
 /// Forward the [Comments] function implementation to the [CommentsRef]
 /// reference type.
@@ -133,6 +191,33 @@ impl Comments {
     ref_fn!(pub fn contains_tag(&self, tag: &Comment) -> bool);
     ref_fn!(pub fn find_inheritdoc_base(&self) -> Option<&'_ str>);
 
+    /// Returns the tags that are not recognized, e.g. misspelled tags.
+    pub fn invalid_tags(&self) -> &[InvalidTag] {
+        &self.invalid
+    }
+
+    /// Returns the doc comments as written in the source, in order.
+    pub fn source_comments(&self) -> &[SourceComment] {
+        &self.sources
+    }
+
+    /// Set the doc comments the natspec comments were parsed from.
+    pub(crate) fn with_sources(mut self, sources: Vec<SourceComment>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Records a doc comment whose tag is not recognized.
+    fn push_invalid(&mut self, doc: DocCommentTag) {
+        // The tag offset points right after the `@`
+        let start = doc.tag_offset.saturating_sub(1);
+        self.invalid.push(InvalidTag {
+            loc: Loc::File(0, start, doc.tag_offset + doc.tag.len()),
+            tag: doc.tag,
+            value: doc.value,
+        });
+    }
+
     /// Attempt to lookup
     ///
     /// Merges two comments collections by inserting [CommentTag] from the second collection
@@ -142,7 +227,12 @@ impl Comments {
         ident: &str,
         inheritdocs: Option<HashMap<String, Self>>,
     ) -> Self {
-        let mut result = Self(Vec::from_iter(self.iter().cloned()));
+        // The merged comments are not written in a single place, so they have no sources
+        let mut result = Self {
+            comments: Vec::from_iter(self.iter().cloned()),
+            invalid: self.invalid.clone(),
+            sources: vec![],
+        };
 
         if let (Some(inheritdocs), Some(base)) = (inheritdocs, self.find_inheritdoc_base()) {
             let key = format!("{base}.{ident}");
@@ -161,15 +251,39 @@ impl Comments {
 
 impl From<Vec<DocCommentTag>> for Comments {
     fn from(value: Vec<DocCommentTag>) -> Self {
-        Self(
-            value
-                .into_iter()
-                .flat_map(Comment::from_doc_comment)
-                .collect(),
-        )
+        let mut comments = Self::default();
+        for doc in value {
+            match CommentTag::from_str(&doc.tag) {
+                // `@custom:` must be followed by the name of the tag
+                Some(CommentTag::Custom(name)) if name.is_empty() => comments.push_invalid(doc),
+                Some(tag) => {
+                    let loc = doc_comment_loc(&doc);
+                    comments.push(Comment::new(tag, doc.value).with_loc(loc));
+                }
+                None => comments.push_invalid(doc),
+            }
+        }
+        comments
     }
 }
 
+/// Returns the location of a doc comment, from the `@` to the end of the first line of the value.
+///
+/// The lines without a tag are an implicit `@notice`, whose offsets are not reliable, so their
+/// location is [Loc::Implicit].
+fn doc_comment_loc(doc: &DocCommentTag) -> Loc {
+    if doc.tag_offset == doc.value_offset {
+        return Loc::Implicit;
+    }
+    let first_line = doc.value.lines().next().unwrap_or_default();
+    let end = if first_line.is_empty() {
+        doc.tag_offset + doc.tag.len()
+    } else {
+        doc.value_offset + first_line.len()
+    };
+    Loc::File(0, doc.tag_offset.saturating_sub(1), end)
+}
+
 /// The collection of references to natspec [Comment] items.
 #[derive(Debug, Default, PartialEq, Deref)]
 pub struct CommentsRef<'a>(Vec<&'a Comment>);
@@ -5,8 +5,9 @@ use forge_fmt::{
 };
 use solang_parser::pt::{
     ContractDefinition, ContractTy, EnumDefinition, ErrorDefinition, EventDefinition,
-    FunctionDefinition, StructDefinition, TypeDefinition, VariableDefinition,
+    FunctionDefinition, Loc, StructDefinition, TypeDefinition, VariableDefinition,
 };
+use std::any::{Any, TypeId};
 
 /// The parsed item.
 #[derive(Debug, PartialEq)]
@@ -148,6 +149,7 @@ impl ParseItem {
     as_inner_source!(pub fn as_enum(&self, Enum) -> EnumDefinition);
     as_inner_source!(pub fn as_error(&self, Error) -> ErrorDefinition);
     as_inner_source!(pub fn as_event(&self, Event) -> EventDefinition);
+    as_inner_source!(pub fn as_type(&self, Type) -> TypeDefinition);
 }
 
 /// A wrapper type around pt token.
@@ -189,4 +191,32 @@ impl ParseSource {
             Self::Type(ty) => ty.name.name.to_owned(),
         }
     }
+
+    /// Get the inner AST node of the source, along with its [TypeId].
+    pub fn node(&self) -> (&dyn Any, TypeId) {
+        match self {
+            Self::Contract(inner) => (inner.as_ref(), TypeId::of::<ContractDefinition>()),
+            Self::Function(inner) => (inner, TypeId::of::<FunctionDefinition>()),
+            Self::Variable(inner) => (inner, TypeId::of::<VariableDefinition>()),
+            Self::Event(inner) => (inner, TypeId::of::<EventDefinition>()),
+            Self::Error(inner) => (inner, TypeId::of::<ErrorDefinition>()),
+            Self::Struct(inner) => (inner, TypeId::of::<StructDefinition>()),
+            Self::Enum(inner) => (inner, TypeId::of::<EnumDefinition>()),
+            Self::Type(inner) => (inner, TypeId::of::<TypeDefinition>()),
+        }
+    }
+
+    /// Get the location of the source
+    pub fn loc(&self) -> Loc {
+        match self {
+            Self::Contract(contract) => contract.loc,
+            Self::Function(func) => func.loc,
+            Self::Variable(var) => var.loc,
+            Self::Event(event) => event.loc,
+            Self::Error(error) => error.loc,
+            Self::Struct(structure) => structure.loc,
+            Self::Enum(enumerable) => enumerable.loc,
+            Self::Type(ty) => ty.loc,
+        }
+    }
 }
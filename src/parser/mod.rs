@@ -25,7 +25,9 @@ pub use item::{ParseItem, ParseSource};
 
 /// Doc comment.
 mod comment;
-pub use comment::{Comment, CommentTag, Comments, CommentsRef};
+pub use comment::{
+    Comment, CommentTag, Comments, CommentsRef, DocStyle, InvalidTag, SourceComment,
+};
 
 /// The documentation parser. This type implements a [Visitor] trait.
 ///
@@ -134,13 +136,51 @@ impl Parser {
             }
         }
 
-        // Filter out `@solidity` and empty tags
+        // Filter out `@solidity` tags, empty tags are kept as invalid tags
         // See https://docs.soliditylang.org/en/v0.8.17/assembly.html#memory-safety
         let res = res
             .into_iter()
-            .filter(|c| c.tag.trim() != "solidity" && !c.tag.trim().is_empty())
+            .filter(|c| c.tag.trim() != "solidity")
             .collect_vec();
-        Ok(res.into())
+
+        // Keep the style of the doc comments, with the same range as `parse_doccomments`
+        let sources = self
+            .comments
+            .iter()
+            .filter_map(|comment| match comment {
+                SolangComment::DocLine(loc, _) => Some((DocStyle::Line, *loc)),
+                SolangComment::DocBlock(loc, _) => Some((DocStyle::Block, *loc)),
+                _ => None,
+            })
+            .filter(|(_, loc)| loc.start() < end && loc.end() >= start)
+            .map(|(style, loc)| SourceComment { style, loc })
+            .collect();
+
+        let mut comments = Comments::from(res).with_sources(sources);
+        self.locate_implicit_notices(&mut comments, start, end);
+        Ok(comments)
+    }
+
+    /// Set the location of the comments without a tag, which are an implicit `@notice`, by
+    /// finding the first line of their value in the source.
+    fn locate_implicit_notices(&self, comments: &mut Comments, start: usize, end: usize) {
+        let end = end.min(self.source.len());
+        let mut offset = start.min(end);
+        for comment in comments.iter_mut() {
+            if comment.loc != Loc::Implicit {
+                offset = comment.loc.end().clamp(offset, end);
+                continue;
+            }
+            let first_line = comment.value.lines().next().unwrap_or_default();
+            if first_line.is_empty() {
+                continue;
+            }
+            if let Some(found) = self.source[offset..end].find(first_line) {
+                let found = offset + found;
+                comment.loc = Loc::File(0, found, found + first_line.len());
+                offset = found + first_line.len();
+            }
+        }
     }
 }
 
@@ -196,10 +236,10 @@ impl Visitor for Parser {
     }
 
     fn visit_function(&mut self, func: &mut FunctionDefinition) -> ParserResult<()> {
-        // If the function parameter doesn't have a name, try to set it with
+        // If the function parameter or return variable doesn't have a name, try to set it with
         // `@custom:name` tag if any was provided
         let mut start_loc = func.loc.start();
-        for (loc, param) in func.params.iter_mut() {
+        for (loc, param) in func.params.iter_mut().chain(func.returns.iter_mut()) {
             if let Some(param) = param {
                 if param.name.is_none() {
                     let docs = self.parse_docs_range(start_loc, loc.end())?;
@@ -368,8 +408,7 @@ mod tests {
 
     #[test]
     fn contract_with_doc_comments() {
-        let items = parse_source(
-            r"
+        let src = r"
             pragma solidity ^0.8.19;
             /// @name Test
             ///  no tag
@@ -385,8 +424,12 @@ mod tests {
             */
                 function test() {}
             }
-        ",
-        );
+        ";
+        let items = parse_source(src);
+        let loc = |text: &str| {
+            let start = src.find(text).unwrap();
+            Loc::File(0, start, start + text.len())
+        };
 
         assert_eq!(items.len(), 1);
 
@@ -395,10 +438,12 @@ mod tests {
         assert_eq!(
             *contract.comments.first().unwrap(),
             Comment::new(CommentTag::Notice, "Cool contract".to_owned())
+                .with_loc(loc("@notice    Cool contract"))
         );
         assert_eq!(
             *contract.comments.get(1).unwrap(),
             Comment::new(CommentTag::Dev, "line one\nline 2".to_owned())
+                .with_loc(loc("@dev line one"))
         );
 
         let function = contract.children.first().unwrap();
@@ -409,6 +454,56 @@ mod tests {
                 CommentTag::Notice,
                 "my function\ni like whitespace".to_owned()
             )
+            .with_loc(loc("my function"))
+        );
+
+        let styles = |comments: &Comments| {
+            comments
+                .source_comments()
+                .iter()
+                .map(|source| source.style)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            styles(&contract.comments),
+            [
+                DocStyle::Line,
+                DocStyle::Line,
+                DocStyle::Line,
+                DocStyle::Line,
+                DocStyle::Block
+            ]
+        );
+        assert_eq!(styles(&function.comments), [DocStyle::Block]);
+    }
+
+    #[test]
+    fn function_with_custom_names() {
+        let items = parse_source(
+            r"
+            contract Contract {
+                function foo(
+                    /** @custom:name amount */ uint256,
+                    address to
+                ) external returns (/** @custom:name success */ bool, uint256) {}
+            }
+        ",
+        );
+
+        let function = items.first().unwrap().children.first().unwrap();
+        let ParseSource::Function(func) = &function.source else {
+            panic!("expected a function");
+        };
+        let names = |params: &solang_parser::pt::ParameterList| {
+            params
+                .iter()
+                .map(|(_, param)| Some(param.as_ref()?.name.as_ref()?.name.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&func.params),
+            [Some("amount".to_owned()), Some("to".to_owned())]
         );
+        assert_eq!(names(&func.returns), [Some("success".to_owned()), None]);
     }
 }
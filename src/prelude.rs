@@ -0,0 +1,63 @@
+//! The types needed to write rules and run natlint with them, in a single import.
+//!
+//! ```
+//! use natlint::prelude::*;
+//!
+//! /// Payable functions must explain where the funds go.
+//! pub struct MissingPayableDev;
+//!
+//! impl Rule for MissingPayableDev {
+//!     type Target = FunctionDefinition;
+//!     const NAME: &'static str = "MissingPayableDev";
+//!     const DESCRIPTION: &'static str = "Payable functions must have a dev comment.";
+//!
+//!     fn check(
+//!         _: Option<&ParseItem>,
+//!         func: &FunctionDefinition,
+//!         comments: &CommentsRef,
+//!     ) -> Option<Violation> {
+//!         let payable = func.attributes.iter().any(|attr| {
+//!             matches!(attr, FunctionAttribute::Mutability(Mutability::Payable(_)))
+//!         });
+//!         (payable && comments.include_tag(CommentTag::Dev).is_empty()).then(|| {
+//!             Violation::new(
+//!                 Self::NAME,
+//!                 Self::DESCRIPTION,
+//!                 ViolationError::MissingComment(CommentTag::Dev),
+//!                 func.loc,
+//!             )
+//!         })
+//!     }
+//! }
+//!
+//! let linter = Linter::builder()
+//!     .rule("missing_payable_dev", MissingPayableDev)
+//!     .build();
+//! let violations = linter.lint("contract Vault { function deposit() external payable {} }")?;
+//! assert!(violations
+//!     .iter()
+//!     .any(|(violation, _)| violation.rule_name == "MissingPayableDev"));
+//! # Ok::<(), eyre::Report>(())
+//! ```
+
+/// The solang parse tree, for the types not re-exported below.
+pub use solang_parser::pt;
+pub use solang_parser::pt::{
+    CodeLocation, ContractDefinition, ContractTy, EnumDefinition, ErrorDefinition, EventDefinition,
+    FunctionAttribute, FunctionDefinition, FunctionTy, Loc, Mutability, StructDefinition,
+    TypeDefinition, VariableAttribute, VariableDefinition, Visibility,
+};
+
+pub use crate::{
+    config::Config,
+    linter::{LintOptions, Linter, LinterBuilder},
+    parser::{Comment, CommentTag, Comments, CommentsRef, ParseItem, ParseSource},
+    project::{Pragma, Project, SourceFile},
+    rules::{
+        context::RuleContext,
+        fix::{Edit, Fix},
+        scope::ItemKind,
+        violation_error::ViolationError,
+        DynRule, FileRule, FileRuleAdapter, Rule, Violation,
+    },
+};
@@ -0,0 +1,609 @@
+//! This module defines the project model, which gives rules access to the other files of the
+//! project.
+//!
+//! A [`Project`] parses the linted files once, along with every file they import. Imports are
+//! resolved relative to the importing file, through the project [`remappings`], or from the
+//! project root. The project then acts as a symbol table for contracts and as their inheritance
+//! graph.
+
+pub mod remappings;
+pub mod signature;
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet, VecDeque},
+    fmt, fs,
+    path::{Component, Path, PathBuf},
+};
+
+use forge_fmt::Visitable;
+use solang_parser::{
+    parse,
+    pt::{Comment as SolangComment, ContractDefinition, Import, Loc, SourceUnit, SourceUnitPart},
+};
+
+use crate::parser::{ParseItem, Parser};
+
+use remappings::{Remapping, RemappingError};
+
+/// A parsed source file.
+#[derive(Debug)]
+pub struct SourceFile {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// The content of the file.
+    pub content: String,
+    /// The top-level items of the file.
+    pub items: Vec<ParseItem>,
+    /// The import directives of the file.
+    pub imports: Vec<Import>,
+    /// The pragma directives of the file.
+    pub pragmas: Vec<Pragma>,
+    /// The parse tree of the file.
+    pub source_unit: SourceUnit,
+    /// All the comments of the file, as parsed by solang, including the ones that are not
+    /// attached to any item.
+    pub comments: Vec<SolangComment>,
+}
+
+/// A pragma directive, e.g. `pragma solidity ^0.8.20;`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pragma {
+    /// The name of the pragma, e.g. `solidity`.
+    pub name: String,
+    /// The value of the pragma, e.g. `^0.8.20`.
+    pub value: String,
+    /// The location of the directive.
+    pub loc: Loc,
+}
+
+impl SourceFile {
+    /// Parses the content of a file.
+    /// # Errors
+    /// Returns an error if the content cannot be parsed.
+    pub fn parse(path: PathBuf, content: String) -> eyre::Result<Self> {
+        let (mut source_unit, comments) =
+            parse(&content, 0).map_err(|e| eyre::eyre!("Failed to parse content: {:?}", e))?;
+
+        let imports = source_unit
+            .0
+            .iter()
+            .filter_map(|part| match part {
+                SourceUnitPart::ImportDirective(import) => Some(import.clone()),
+                _ => None,
+            })
+            .collect();
+        let pragmas = source_unit
+            .0
+            .iter()
+            .filter_map(|part| match part {
+                SourceUnitPart::PragmaDirective(loc, Some(name), Some(value)) => Some(Pragma {
+                    name: name.name.clone(),
+                    value: value.string.clone(),
+                    loc: *loc,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let mut parser = Parser::new(comments.clone(), content.clone());
+        source_unit
+            .visit(&mut parser)
+            .map_err(|e| eyre::eyre!("Failed to visit: {:?}", e))?;
+
+        Ok(Self {
+            path,
+            content,
+            items: parser.items(),
+            imports,
+            pragmas,
+            source_unit,
+            comments,
+        })
+    }
+
+    /// Returns the value of the pragma with the given name, e.g. `^0.8.20` for `solidity`.
+    #[must_use]
+    pub fn pragma(&self, name: &str) -> Option<&str> {
+        self.pragmas
+            .iter()
+            .find(|pragma| pragma.name == name)
+            .map(|pragma| pragma.value.as_str())
+    }
+
+    /// Returns the license expression of the `SPDX-License-Identifier` comment of the file, e.g.
+    /// `MIT` or `MIT OR Apache-2.0`.
+    #[must_use]
+    pub fn spdx_license(&self) -> Option<&str> {
+        self.content.lines().find_map(|line| {
+            let (_, license) = line.split_once("SPDX-License-Identifier:")?;
+            let license = license.trim().trim_end_matches("*/").trim_end();
+            (!license.is_empty()).then_some(license)
+        })
+    }
+
+    /// Returns the contract declared in the file with the given name.
+    #[must_use]
+    pub fn contract(&self, name: &str) -> Option<&ParseItem> {
+        self.items.iter().find(|item| {
+            item.as_contract()
+                .and_then(|contract| contract.name.as_ref())
+                .is_some_and(|id| id.name == name)
+        })
+    }
+}
+
+/// A contract declared somewhere in the project.
+#[derive(Clone, Copy, Debug)]
+pub struct ContractRef<'a> {
+    /// The file the contract is declared in.
+    pub path: &'a Path,
+    /// The parsed contract item.
+    pub item: &'a ParseItem,
+}
+
+impl<'a> ContractRef<'a> {
+    /// Returns the contract definition.
+    ///
+    /// # Panics
+    /// Panics if the item is not a contract, which [`Project`] never returns.
+    #[must_use]
+    pub fn definition(&self) -> &'a ContractDefinition {
+        self.item.as_contract().expect("item is a contract")
+    }
+
+    /// Returns the name of the contract.
+    #[must_use]
+    pub fn name(&self) -> &'a str {
+        self.definition()
+            .name
+            .as_ref()
+            .map_or("", |id| id.name.as_str())
+    }
+}
+
+impl PartialEq for ContractRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.name() == other.name()
+    }
+}
+
+/// An ancestor in the inheritance graph of a contract.
+#[derive(Clone, Debug)]
+pub struct Ancestor<'a> {
+    /// The name of the base, as written in the inheritance list.
+    pub name: String,
+    /// The base contract, or `None` if it could not be resolved.
+    pub contract: Option<ContractRef<'a>>,
+}
+
+impl Ancestor<'_> {
+    /// Returns `true` if `name` refers to this ancestor.
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name
+            || self.name.rsplit('.').next() == Some(name)
+            || self
+                .contract
+                .is_some_and(|contract| contract.name() == name)
+    }
+}
+
+/// An import that could not be loaded, so the checks across files ignore it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedImport {
+    /// The path of the importing file.
+    pub from: PathBuf,
+    /// The imported path, as written in the import directive.
+    pub import: String,
+    /// Why the import was skipped.
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedImport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: skipped import \"{}\": {}",
+            self.from.display(),
+            self.import,
+            self.reason
+        )
+    }
+}
+
+/// A target file that could not be loaded, so it is not linted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedFile {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// Why the file was skipped.
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.reason)
+    }
+}
+
+/// The files of a project, along with the files they import.
+#[derive(Debug)]
+pub struct Project {
+    root: PathBuf,
+    remappings: Vec<Remapping>,
+    remapping_errors: Vec<RemappingError>,
+    files: BTreeMap<PathBuf, SourceFile>,
+    targets: BTreeSet<PathBuf>,
+    skipped: Vec<SkippedImport>,
+    skipped_files: Vec<SkippedFile>,
+}
+
+impl Project {
+    /// Loads the project at `root`, parsing the target files and every file they import.
+    /// The paths are canonicalized, so a file is loaded once however its path is written.
+    ///
+    /// Nothing stops the loading: the invalid remappings, the target files and the imports that
+    /// cannot be read or parsed are skipped, see [`Project::remapping_errors`],
+    /// [`Project::skipped_files`] and [`Project::skipped_imports`].
+    #[must_use]
+    pub fn load(root: &Path, targets: impl IntoIterator<Item = PathBuf>) -> Self {
+        let (remappings, remapping_errors) = remappings::load(root);
+        let mut project = Self {
+            root: canonicalize(root),
+            remappings,
+            remapping_errors,
+            files: BTreeMap::new(),
+            targets: BTreeSet::new(),
+            skipped: vec![],
+            skipped_files: vec![],
+        };
+
+        let mut pending = VecDeque::new();
+        for target in targets {
+            let path = canonicalize(&target);
+            if project.targets.contains(&path) {
+                continue;
+            }
+            let file = fs::read_to_string(&path)
+                .map_err(eyre::Report::from)
+                .and_then(|content| SourceFile::parse(path.clone(), content));
+            match file {
+                Ok(file) => {
+                    pending.push_back(path.clone());
+                    project.targets.insert(path.clone());
+                    project.files.insert(path, file);
+                }
+                Err(e) => project.skipped_files.push(SkippedFile {
+                    path,
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        while let Some(path) = pending.pop_front() {
+            let imports = project.files[&path]
+                .imports
+                .iter()
+                .filter_map(|import| {
+                    let literal = import.literal()?.string.clone();
+                    Some((project.resolve_import(&path, import), literal))
+                })
+                .collect::<Vec<_>>();
+
+            for (resolved, literal) in imports {
+                let skip = |reason: String| SkippedImport {
+                    from: path.clone(),
+                    import: literal.clone(),
+                    reason,
+                };
+                let Some(import) = resolved else {
+                    project.skipped.push(skip("file not found".to_owned()));
+                    continue;
+                };
+                if project.files.contains_key(&import) {
+                    continue;
+                }
+                let file = fs::read_to_string(&import)
+                    .map_err(eyre::Report::from)
+                    .and_then(|content| SourceFile::parse(import.clone(), content));
+                match file {
+                    Ok(file) => {
+                        project.files.insert(import.clone(), file);
+                        pending.push_back(import);
+                    }
+                    Err(e) => project.skipped.push(skip(e.to_string())),
+                }
+            }
+        }
+
+        project
+    }
+
+    /// Returns the imports that could not be loaded, in the order they were found.
+    #[must_use]
+    pub fn skipped_imports(&self) -> &[SkippedImport] {
+        &self.skipped
+    }
+
+    /// Returns the target files that could not be loaded, in the order they were given.
+    #[must_use]
+    pub fn skipped_files(&self) -> &[SkippedFile] {
+        &self.skipped_files
+    }
+
+    /// Returns the errors of the remappings files and the invalid remappings, which the imports
+    /// are resolved without.
+    #[must_use]
+    pub fn remapping_errors(&self) -> &[RemappingError] {
+        &self.remapping_errors
+    }
+
+    /// Returns the root directory of the project.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the files that were requested to be loaded, i.e. not only imported.
+    pub fn targets(&self) -> impl Iterator<Item = &SourceFile> {
+        self.targets.iter().map(|path| &self.files[path])
+    }
+
+    /// Returns the loaded file at `path`.
+    #[must_use]
+    pub fn file(&self, path: &Path) -> Option<&SourceFile> {
+        self.files.get(&self.key(path))
+    }
+
+    /// Returns the path `path` is loaded at, avoiding to canonicalize the paths that already are.
+    fn key(&self, path: &Path) -> PathBuf {
+        if self.files.contains_key(path) {
+            path.to_path_buf()
+        } else {
+            canonicalize(path)
+        }
+    }
+
+    /// Resolves the file an import directive of the file at `from` refers to.
+    /// Returns `None` if the imported file doesn't exist.
+    #[must_use]
+    pub fn resolve_import(&self, from: &Path, import: &Import) -> Option<PathBuf> {
+        let import = &import.literal()?.string;
+
+        let candidates = if import.starts_with("./") || import.starts_with("../") {
+            vec![from.parent().unwrap_or(&self.root).join(import)]
+        } else {
+            let relative = from.strip_prefix(&self.root).unwrap_or(from);
+            let remapped = self
+                .remappings
+                .iter()
+                .filter_map(|remapping| {
+                    let remapped = remapping.apply(relative, import)?;
+                    Some((remapping.prefix.len(), remapped))
+                })
+                // The longest matching prefix takes precedence, the first one on ties
+                .rev()
+                .max_by_key(|(len, _)| *len)
+                .map(|(_, remapped)| self.root.join(remapped));
+
+            remapped
+                .into_iter()
+                .chain([self.root.join(import), self.root.join("lib").join(import)])
+                .collect()
+        };
+
+        candidates
+            .into_iter()
+            .map(|candidate| canonicalize(&candidate))
+            .find(|candidate| self.files.contains_key(candidate) || candidate.is_file())
+    }
+
+    /// Resolves the contract visible as `name` in the file at `from`, whether it is declared
+    /// there or imported.
+    #[must_use]
+    pub fn resolve_contract(&self, from: &Path, name: &str) -> Option<ContractRef<'_>> {
+        self.resolve_contract_in(&self.key(from), name, &mut HashSet::new())
+    }
+
+    fn resolve_contract_in(
+        &self,
+        from: &Path,
+        name: &str,
+        visited: &mut HashSet<(PathBuf, String)>,
+    ) -> Option<ContractRef<'_>> {
+        if !visited.insert((from.to_path_buf(), name.to_owned())) {
+            return None;
+        }
+        let (path, file) = self.files.get_key_value(from)?;
+
+        // `Unit.Name` refers to a contract of a file imported as `Unit`
+        if let Some((unit, rest)) = name.split_once('.') {
+            return file.imports.iter().find_map(|import| match import {
+                Import::GlobalSymbol(_, alias, _) if alias.name == unit => {
+                    let target = self.resolve_import(from, import)?;
+                    self.resolve_contract_in(&target, rest, visited)
+                }
+                _ => None,
+            });
+        }
+
+        if let Some(item) = file.contract(name) {
+            return Some(ContractRef { path, item });
+        }
+
+        file.imports.iter().find_map(|import| match import {
+            Import::Plain(..) => {
+                let target = self.resolve_import(from, import)?;
+                self.resolve_contract_in(&target, name, visited)
+            }
+            Import::Rename(_, symbols, _) => {
+                let (symbol, _) = symbols
+                    .iter()
+                    .find(|(symbol, alias)| alias.as_ref().unwrap_or(symbol).name == name)?;
+                let target = self.resolve_import(from, import)?;
+                self.resolve_contract_in(&target, &symbol.name, visited)
+            }
+            Import::GlobalSymbol(..) => None,
+        })
+    }
+
+    /// Returns all the ancestors of the contract, the direct bases first.
+    #[must_use]
+    pub fn ancestors<'a>(&'a self, contract: &ContractRef<'a>) -> Vec<Ancestor<'a>> {
+        let mut ancestors: Vec<Ancestor<'a>> = vec![];
+        let mut pending = VecDeque::from([*contract]);
+        let mut visited = vec![*contract];
+
+        while let Some(next) = pending.pop_front() {
+            for base in &next.definition().base {
+                let name = base
+                    .name
+                    .identifiers
+                    .iter()
+                    .map(|id| id.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                let resolved = self.resolve_contract(next.path, &name);
+
+                if let Some(resolved) = resolved {
+                    if visited.contains(&resolved) {
+                        continue;
+                    }
+                    visited.push(resolved);
+                    pending.push_back(resolved);
+                }
+                ancestors.push(Ancestor {
+                    name,
+                    contract: resolved,
+                });
+            }
+        }
+
+        ancestors
+    }
+
+    /// Returns the member of the `base` ancestor that an `@inheritdoc` comment of a member with
+    /// the given signature refers to, i.e. the function or public variable with the same selector.
+    #[must_use]
+    pub fn inheritdoc_target<'a>(
+        &'a self,
+        contract: &ContractRef<'a>,
+        base: &str,
+        signature: &str,
+    ) -> Option<(ContractRef<'a>, &'a ParseItem)> {
+        let base = self
+            .ancestors(contract)
+            .into_iter()
+            .find(|ancestor| ancestor.is_named(base))?
+            .contract?;
+
+        signature::find_member(base.item, signature).map(|member| (base, member))
+    }
+
+    /// Returns whether the contract or one of its ancestors declares a member with the selector of
+    /// `signature`.
+    /// Returns `None` if it is unknown because some ancestors could not be resolved.
+    #[must_use]
+    pub fn declares_member(&self, contract: &ContractRef, signature: &str) -> Option<bool> {
+        if signature::find_member(contract.item, signature).is_some() {
+            return Some(true);
+        }
+
+        let mut known = true;
+        for ancestor in self.ancestors(contract) {
+            match ancestor.contract {
+                Some(base) if signature::find_member(base.item, signature).is_some() => {
+                    return Some(true);
+                }
+                Some(_) => (),
+                None => known = false,
+            }
+        }
+
+        known.then_some(false)
+    }
+
+    /// Returns whether the contract inherits from `base`, directly or indirectly.
+    /// Returns `None` if it is unknown because some ancestors could not be resolved.
+    #[must_use]
+    pub fn inherits_from(&self, contract: &ContractRef, base: &str) -> Option<bool> {
+        let ancestors = self.ancestors(contract);
+        if ancestors.iter().any(|ancestor| ancestor.is_named(base)) {
+            return Some(true);
+        }
+
+        ancestors
+            .iter()
+            .all(|ancestor| ancestor.contract.is_some())
+            .then_some(false)
+    }
+}
+
+/// Canonicalizes a path, or lexically normalizes it if it doesn't exist.
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| normalize(path))
+}
+
+/// Lexically normalizes a path, removing `.` and resolving `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, SourceFile};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn file_metadata() {
+        let file = SourceFile::parse(
+            PathBuf::from("Test.sol"),
+            r"
+            // SPDX-License-Identifier: MIT OR Apache-2.0
+            pragma solidity ^0.8.20;
+            pragma abicoder v2;
+
+            contract Test {}
+            "
+            .to_owned(),
+        )
+        .unwrap();
+
+        assert_eq!(file.pragma("solidity"), Some("^0.8.20"));
+        assert_eq!(file.pragma("abicoder"), Some("v2"));
+        assert_eq!(file.pragma("experimental"), None);
+        assert_eq!(file.spdx_license(), Some("MIT OR Apache-2.0"));
+
+        let file = SourceFile::parse(PathBuf::new(), "contract Test {}".to_owned()).unwrap();
+        assert_eq!(file.pragma("solidity"), None);
+        assert_eq!(file.spdx_license(), None);
+    }
+
+    #[test]
+    fn normalize_path() {
+        assert_eq!(
+            normalize(Path::new("./src/../lib/./oz/IERC20.sol")),
+            PathBuf::from("lib/oz/IERC20.sol")
+        );
+        assert_eq!(
+            normalize(Path::new("../lib/IERC20.sol")),
+            PathBuf::from("../lib/IERC20.sol")
+        );
+        assert_eq!(normalize(Path::new(".")), PathBuf::new());
+    }
+}
@@ -0,0 +1,217 @@
+//! Import remappings, as configured for Foundry projects.
+//!
+//! Remappings are read from `remappings.txt` and from the `remappings` of the default profile in
+//! `foundry.toml`. Every dependency in the `libs` directories is also remapped by its name, the
+//! way Foundry auto-detects them.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+/// Errors that can occur while reading remappings.
+#[derive(Error, Debug)]
+pub enum RemappingError {
+    /// The remapping has no `=` separator.
+    #[error("Invalid remapping `{0}`: expected `prefix=target`")]
+    MissingSeparator(String),
+    /// Error parsing `foundry.toml`.
+    #[error("Failed to parse foundry.toml: {0}")]
+    FoundryToml(#[from] toml::de::Error),
+    /// Error reading a remappings file.
+    #[error("Failed to read remappings: {0}")]
+    Read(#[from] std::io::Error),
+}
+
+/// A single remapping, `[context:]prefix=target`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping {
+    /// Only the files under this path (relative to the project root) use the remapping.
+    pub context: Option<String>,
+    /// The import prefix that is remapped.
+    pub prefix: String,
+    /// The path the prefix is replaced with, relative to the project root.
+    pub target: String,
+}
+
+impl FromStr for Remapping {
+    type Err = RemappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lhs, target) = s
+            .trim()
+            .split_once('=')
+            .ok_or_else(|| RemappingError::MissingSeparator(s.to_owned()))?;
+        let (context, prefix) = match lhs.split_once(':') {
+            Some((context, prefix)) => (Some(context.to_owned()), prefix),
+            None => (None, lhs),
+        };
+
+        Ok(Self {
+            context,
+            prefix: prefix.to_owned(),
+            target: target.to_owned(),
+        })
+    }
+}
+
+impl Remapping {
+    /// Applies the remapping to an import path of a file, both relative to the project root.
+    /// Returns `None` if the remapping doesn't apply.
+    #[must_use]
+    pub fn apply(&self, from: &Path, import: &str) -> Option<String> {
+        if let Some(context) = &self.context {
+            if !from.starts_with(context) {
+                return None;
+            }
+        }
+
+        import
+            .strip_prefix(&self.prefix)
+            .map(|rest| format!("{}{rest}", self.target))
+    }
+}
+
+/// Reads the remappings of the project at `root`.
+///
+/// Explicit remappings come first, followed by the ones detected in the `libs` directories.
+/// The remappings files that cannot be read or parsed, and the invalid remappings, are skipped
+/// and returned along with the others.
+#[must_use]
+pub fn load(root: &Path) -> (Vec<Remapping>, Vec<RemappingError>) {
+    let mut remappings = vec![];
+    let mut errors = vec![];
+    let mut libs = vec!["lib".to_owned()];
+
+    let remappings_txt = root.join("remappings.txt");
+    if remappings_txt.is_file() {
+        match fs::read_to_string(remappings_txt) {
+            Ok(content) => {
+                for line in content.lines() {
+                    if !line.trim().is_empty() && !line.trim_start().starts_with('#') {
+                        match line.parse() {
+                            Ok(remapping) => remappings.push(remapping),
+                            Err(e) => errors.push(e),
+                        }
+                    }
+                }
+            }
+            Err(e) => errors.push(e.into()),
+        }
+    }
+
+    let foundry_toml = root.join("foundry.toml");
+    if foundry_toml.is_file() {
+        match read_foundry_toml(&foundry_toml) {
+            Ok(config) => {
+                let profile = config
+                    .get("profile")
+                    .and_then(|profile| profile.get("default"));
+                let strings = |key: &str| -> Option<Vec<String>> {
+                    profile?.get(key)?.as_array().map(|values| {
+                        values
+                            .iter()
+                            .filter_map(|value| value.as_str().map(str::to_owned))
+                            .collect()
+                    })
+                };
+
+                for remapping in strings("remappings").unwrap_or_default() {
+                    match remapping.parse() {
+                        Ok(remapping) => remappings.push(remapping),
+                        Err(e) => errors.push(e),
+                    }
+                }
+                if let Some(configured) = strings("libs") {
+                    libs = configured;
+                }
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    for lib in libs {
+        let Ok(entries) = fs::read_dir(root.join(&lib)) else {
+            continue;
+        };
+        let mut dependencies = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        dependencies.sort();
+
+        for name in dependencies {
+            let dir = PathBuf::from(&lib).join(&name);
+            let target = if root.join(&dir).join("src").is_dir() {
+                dir.join("src")
+            } else {
+                dir
+            };
+            remappings.push(Remapping {
+                context: None,
+                prefix: format!("{name}/"),
+                target: format!("{}/", target.to_string_lossy()),
+            });
+        }
+    }
+
+    (remappings, errors)
+}
+
+/// Reads and parses `foundry.toml`.
+fn read_foundry_toml(path: &Path) -> Result<toml::Table, RemappingError> {
+    Ok(toml::from_str(&fs::read_to_string(path)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Remapping;
+    use std::path::Path;
+
+    #[test]
+    fn parse_remapping() {
+        let remapping: Remapping = "@oz/=lib/openzeppelin-contracts/contracts/"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            remapping,
+            Remapping {
+                context: None,
+                prefix: "@oz/".to_owned(),
+                target: "lib/openzeppelin-contracts/contracts/".to_owned(),
+            }
+        );
+
+        let remapping: Remapping = "src/:@oz/=lib/oz/".parse().unwrap();
+        assert_eq!(remapping.context.as_deref(), Some("src/"));
+        assert_eq!(remapping.prefix, "@oz/");
+
+        assert!("@oz/".parse::<Remapping>().is_err());
+    }
+
+    #[test]
+    fn apply_remapping() {
+        let remapping: Remapping = "@oz/=lib/oz/contracts/".parse().unwrap();
+        assert_eq!(
+            remapping.apply(Path::new("src/Token.sol"), "@oz/token/IERC20.sol"),
+            Some("lib/oz/contracts/token/IERC20.sol".to_owned())
+        );
+        assert_eq!(
+            remapping.apply(Path::new("src/Token.sol"), "./IERC20.sol"),
+            None
+        );
+
+        let remapping: Remapping = "test/:@oz/=lib/oz/contracts/".parse().unwrap();
+        assert_eq!(
+            remapping.apply(Path::new("src/Token.sol"), "@oz/token/IERC20.sol"),
+            None
+        );
+        assert!(remapping
+            .apply(Path::new("test/Token.t.sol"), "@oz/token/IERC20.sol")
+            .is_some());
+    }
+}
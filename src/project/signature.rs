@@ -0,0 +1,139 @@
+//! Function signatures and selectors, used to match the members of a contract with the members
+//! of its bases.
+//!
+//! Parameter types are written as declared: user-defined types are not resolved to their ABI
+//! types, which is enough to match declarations that refer to the types by the same names.
+
+use alloy_primitives::keccak256;
+use itertools::Itertools;
+use solang_parser::pt::{Expression, FunctionDefinition, FunctionTy, Type, VariableDefinition};
+
+use crate::parser::{ParseItem, ParseSource};
+
+/// Returns the signature of a function, e.g. `transfer(address,uint256)`.
+/// Returns `None` for constructors, modifiers and the other functions without a selector.
+#[must_use]
+pub fn function_signature(func: &FunctionDefinition) -> Option<String> {
+    if func.ty != FunctionTy::Function {
+        return None;
+    }
+    let name = &func.name.as_ref()?.name;
+    let types = func
+        .params
+        .iter()
+        .filter_map(|(_, param)| param.as_ref())
+        .map(|param| param.ty.to_string())
+        .join(",");
+
+    Some(format!("{name}({types})"))
+}
+
+/// Returns the signature of the getter of a variable, e.g. `balanceOf(address)` for a
+/// `mapping(address => uint256) balanceOf`.
+#[must_use]
+pub fn getter_signature(var: &VariableDefinition) -> Option<String> {
+    let name = &var.name.as_ref()?.name;
+
+    let mut types = vec![];
+    let mut ty = &var.ty;
+    loop {
+        match ty {
+            Expression::Type(_, Type::Mapping { key, value, .. }) => {
+                types.push(key.to_string());
+                ty = value;
+            }
+            Expression::ArraySubscript(_, inner, _) => {
+                types.push("uint256".to_owned());
+                ty = inner;
+            }
+            _ => break,
+        }
+    }
+
+    Some(format!("{name}({})", types.join(",")))
+}
+
+/// Returns the signature of a function or a variable getter, or `None` for the other items.
+#[must_use]
+pub fn signature(source: &ParseSource) -> Option<String> {
+    match source {
+        ParseSource::Function(func) => function_signature(func),
+        ParseSource::Variable(var) => getter_signature(var),
+        _ => None,
+    }
+}
+
+/// Returns the member of a contract with the same selector as `signature`.
+#[must_use]
+pub fn find_member<'a>(contract: &'a ParseItem, signature: &str) -> Option<&'a ParseItem> {
+    let selector = self::selector(signature);
+    contract.children.iter().find(|child| {
+        self::signature(&child.source).is_some_and(|member| self::selector(&member) == selector)
+    })
+}
+
+/// Returns the selector of a signature, i.e. the first 4 bytes of its hash.
+#[must_use]
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{selector, signature};
+    use crate::parser::{ParseItem, Parser};
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Vec<ParseItem> {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc.items()
+    }
+
+    #[test]
+    fn signatures() {
+        let items = parse_source(
+            r"
+            contract Test {
+                function transfer(address to, uint amount) external returns (bool) {}
+                function batch(uint256[] calldata ids, IERC20 token) external {}
+                mapping(address => mapping(uint256 => bool)) public allowed;
+                uint256[] public values;
+                uint256 public total;
+                constructor() {}
+                receive() external payable {}
+            }
+            ",
+        );
+        let signatures = items[0]
+            .children
+            .iter()
+            .map(|child| signature(&child.source))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            signatures,
+            [
+                Some("transfer(address,uint256)".to_owned()),
+                Some("batch(uint256[],IERC20)".to_owned()),
+                Some("allowed(address,uint256)".to_owned()),
+                Some("values(uint256)".to_owned()),
+                Some("total()".to_owned()),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn selectors() {
+        assert_eq!(
+            selector("transfer(address,uint256)"),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+        assert_eq!(selector("totalSupply()"), [0x18, 0x16, 0x0d, 0xdd]);
+    }
+}
@@ -0,0 +1,580 @@
+use std::any::{Any, TypeId};
+
+use solang_parser::pt::Loc;
+
+use crate::{
+    parser::{CommentsRef, DocStyle, ParseItem, SourceComment},
+    rules::{
+        context::RuleContext,
+        fix::{Edit, Fix},
+        violation_error::ViolationError,
+        DynRule, Violation,
+    },
+};
+
+/// The preferred style of the doc comments.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PreferredStyle {
+    /// Every doc comment uses `///`.
+    TripleSlash,
+    /// Doc comments spanning several lines use `/** */`, a single line may use `///`.
+    Block,
+    /// Both styles are allowed, as long as the docs of an item do not mix them.
+    #[default]
+    Either,
+}
+
+/// Configuration for the style of the doc comments.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct CommentStyleConfig {
+    /// The preferred style of the doc comments.
+    #[serde(default)]
+    pub prefer: PreferredStyle,
+    /// Whether the lines of `/** */` doc comments must start with a ` * ` aligned under the `/**`.
+    #[serde(default = "CommentStyleConfig::default_block_alignment")]
+    pub block_alignment: bool,
+}
+
+impl Default for CommentStyleConfig {
+    fn default() -> Self {
+        Self {
+            prefer: PreferredStyle::default(),
+            block_alignment: true,
+        }
+    }
+}
+
+impl CommentStyleConfig {
+    fn default_block_alignment() -> bool {
+        Self::default().block_alignment
+    }
+}
+
+/// This rule requires that the doc comments of an item follow the preferred style, and that the
+/// lines of `/** */` doc comments are aligned.
+///
+/// The fix converts the doc comments to the preferred style and realigns the ` * ` gutters. It is
+/// only offered for doc comments on their own lines.
+pub struct CommentStyle {
+    prefer: PreferredStyle,
+    block_alignment: bool,
+}
+
+impl CommentStyle {
+    /// The name of the rule.
+    pub const NAME: &'static str = "CommentStyle";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str = "Doc comments must follow the configured style.";
+
+    /// Create the rule from its configuration.
+    #[must_use]
+    pub const fn new(config: &CommentStyleConfig) -> Self {
+        Self {
+            prefer: config.prefer,
+            block_alignment: config.block_alignment,
+        }
+    }
+
+    /// Check the style of the doc comments of the item.
+    #[must_use]
+    pub fn check(&self, ctx: &RuleContext, item: &ParseItem) -> Option<Violation> {
+        let sources = item.comments.source_comments();
+        let text = ctx.source();
+
+        let (mut errors, mut edits, converted) = self.convert(text, sources);
+        if self.block_alignment {
+            for source in sources {
+                if source.style != DocStyle::Block || converted.iter().any(|loc| loc == &source.loc)
+                {
+                    continue;
+                }
+                let Some(alignment) = text.and_then(|text| align(text, &source.loc)) else {
+                    continue;
+                };
+                if !alignment.is_empty() {
+                    errors.push((ViolationError::MisalignedDocComment, source.loc));
+                    edits.extend(alignment);
+                }
+            }
+        }
+
+        let (error, loc) = errors.into_iter().next()?;
+        let violation = Violation::new(Self::NAME, Self::DESCRIPTION, error, loc);
+        if edits.is_empty() {
+            Some(violation)
+        } else {
+            edits.sort_by_key(|edit| edit.start);
+            Some(violation.with_fix(Fix::new(edits)))
+        }
+    }
+
+    /// Returns the style errors of the doc comments, the edits converting them to the preferred
+    /// style, and the locations of the converted doc comments.
+    fn convert(
+        &self,
+        text: Option<&str>,
+        sources: &[SourceComment],
+    ) -> (Vec<(ViolationError, Loc)>, Vec<Edit>, Vec<Loc>) {
+        let target = match self.prefer {
+            PreferredStyle::TripleSlash => DocStyle::Line,
+            PreferredStyle::Block => DocStyle::Block,
+            PreferredStyle::Either => match sources.first() {
+                Some(first) if sources.iter().any(|source| source.style != first.style) => {
+                    first.style
+                }
+                _ => return (vec![], vec![], vec![]),
+            },
+        };
+        let error = |style| match self.prefer {
+            PreferredStyle::Either => ViolationError::MixedDocCommentStyles,
+            _ => ViolationError::DocCommentStyle(style),
+        };
+
+        let mut errors = vec![];
+        let mut edits = vec![];
+        let mut converted = vec![];
+        match target {
+            DocStyle::Line => {
+                for source in sources.iter().filter(|s| s.style == DocStyle::Block) {
+                    errors.push((error(DocStyle::Line), source.loc));
+                    converted.push(source.loc);
+                    edits.extend(text.and_then(|text| to_triple_slash(text, &source.loc)));
+                }
+            }
+            DocStyle::Block => {
+                // A single `///` line is allowed, unless the item mixes the styles
+                let min_len = if self.prefer == PreferredStyle::Block {
+                    2
+                } else {
+                    1
+                };
+                for run in line_runs(text, sources) {
+                    if run.len() >= min_len {
+                        errors.push((error(DocStyle::Block), run[0].loc));
+                        edits.extend(text.and_then(|text| to_block(text, &run)));
+                    }
+                }
+            }
+        }
+
+        (errors, edits, converted)
+    }
+}
+
+impl DynRule for CommentStyle {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, ctx: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(ctx, item)
+    }
+}
+
+/// Returns the runs of `///` doc comments on consecutive lines.
+fn line_runs<'a>(text: Option<&str>, sources: &'a [SourceComment]) -> Vec<Vec<&'a SourceComment>> {
+    let mut runs: Vec<Vec<&SourceComment>> = vec![];
+    let mut previous: Option<&SourceComment> = None;
+    for source in sources {
+        if source.style == DocStyle::Block {
+            previous = None;
+            continue;
+        }
+        let adjacent = previous.is_some_and(|previous| {
+            text.is_none_or(|text| {
+                text[previous.loc.end()..source.loc.start()]
+                    .trim()
+                    .is_empty()
+            })
+        });
+        match runs.last_mut() {
+            Some(run) if adjacent => run.push(source),
+            _ => runs.push(vec![source]),
+        }
+        previous = Some(source);
+    }
+    runs
+}
+
+/// Returns the indentation of the line of `offset`, or `None` if some code precedes it.
+fn indent(text: &str, offset: usize) -> Option<&str> {
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &text[line_start..offset];
+    indent.trim().is_empty().then_some(indent)
+}
+
+/// Returns `true` if only whitespace follows `offset` on its line.
+fn ends_line(text: &str, offset: usize) -> bool {
+    text[offset..]
+        .split('\n')
+        .next()
+        .is_some_and(|rest| rest.trim().is_empty())
+}
+
+/// Returns the lines of a `/** */` doc comment, without the delimiters and the ` * ` gutters.
+fn block_lines(comment: &str) -> Vec<&str> {
+    let inner = &comment[3..comment.len() - 2];
+    let mut lines = inner
+        .lines()
+        .map(|line| {
+            let line = line.trim_start();
+            let line = line.strip_prefix('*').unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let leading = lines.iter().take_while(|line| line.is_empty()).count();
+    lines.split_off(leading)
+}
+
+/// Returns the edit converting a `/** */` doc comment to `///` lines.
+fn to_triple_slash(text: &str, loc: &Loc) -> Option<Edit> {
+    let indent = indent(text, loc.start())?;
+    if !ends_line(text, loc.end()) {
+        return None;
+    }
+
+    let replacement = block_lines(&text[loc.start()..loc.end()])
+        .into_iter()
+        .map(|line| {
+            if line.is_empty() {
+                "///".to_owned()
+            } else {
+                format!("/// {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(&format!("\n{indent}"));
+
+    Some(Edit::replace(loc.start(), loc.end(), replacement))
+}
+
+/// Returns the edit converting a run of `///` doc comments to a `/** */` doc comment.
+fn to_block(text: &str, run: &[&SourceComment]) -> Option<Edit> {
+    let (first, last) = (run.first()?, run.last()?);
+    let indent = indent(text, first.loc.start())?;
+
+    let lines = run
+        .iter()
+        .map(|source| {
+            let line = &text[source.loc.start() + 3..source.loc.end()];
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>();
+
+    let replacement = if let [line] = lines.as_slice() {
+        format!("/** {line} */")
+    } else {
+        let body = lines
+            .iter()
+            .map(|line| {
+                if line.is_empty() {
+                    format!("{indent} *")
+                } else {
+                    format!("{indent} * {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("/**\n{body}\n{indent} */")
+    };
+
+    Some(Edit::replace(
+        first.loc.start(),
+        last.loc.end(),
+        replacement,
+    ))
+}
+
+/// Returns the edits aligning the lines of a `/** */` doc comment under its `/**`, which are empty
+/// if it is aligned, or `None` if some code precedes it.
+fn align(text: &str, loc: &Loc) -> Option<Vec<Edit>> {
+    let indent = indent(text, loc.start())?;
+
+    let mut edits = vec![];
+    let mut offset = loc.start();
+    for (i, raw_line) in text[loc.start()..loc.end()].split('\n').enumerate() {
+        let line = raw_line.strip_suffix('\r').unwrap_or(raw_line);
+        if i > 0 {
+            let trimmed = line.trim_start();
+            let expected = match trimmed.strip_prefix('*') {
+                _ if trimmed.starts_with("*/") => format!("{indent} {trimmed}"),
+                Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+                    format!("{indent} *{rest}")
+                }
+                Some(rest) => format!("{indent} * {rest}"),
+                None if trimmed.is_empty() => format!("{indent} *"),
+                None => format!("{indent} * {trimmed}"),
+            };
+            if line != expected {
+                edits.push(Edit::replace(offset, offset + line.len(), expected));
+            }
+        }
+        offset += raw_line.len() + 1;
+    }
+
+    Some(edits)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{CommentStyle, CommentStyleConfig, PreferredStyle};
+    use crate::{
+        parser::{DocStyle, ParseItem},
+        project::SourceFile,
+        rules::{context::RuleContext, fix::apply_fixes, violation_error::ViolationError},
+    };
+
+    /// Returns the errors of the items of the first contract and of the contract itself, and
+    /// the fixed source.
+    fn check(
+        prefer: PreferredStyle,
+        block_alignment: bool,
+        src: &str,
+    ) -> (Vec<ViolationError>, String) {
+        let file = SourceFile::parse(PathBuf::new(), src.to_owned()).unwrap();
+        let rule = CommentStyle::new(&CommentStyleConfig {
+            prefer,
+            block_alignment,
+        });
+        let ctx = RuleContext::from_file(&file);
+
+        let contract = &file.items[0];
+        let items = std::iter::once(contract).chain(&contract.children);
+        let violations = items
+            .filter_map(|item: &ParseItem| rule.check(&ctx, item))
+            .collect::<Vec<_>>();
+
+        let fixes = violations
+            .iter()
+            .filter_map(|violation| violation.fix.as_ref())
+            .collect::<Vec<_>>();
+        let (output, _) = apply_fixes(src, &fixes);
+        let errors = violations
+            .into_iter()
+            .map(|violation| violation.error)
+            .collect();
+        (errors, output)
+    }
+
+    macro_rules! test_commentstyle {
+        ($name:ident, $prefer:expr, $alignment:expr, $source:expr, $errors:expr, $fixed:expr) => {
+            #[test]
+            fn $name() {
+                let errors: Vec<ViolationError> = $errors;
+                assert_eq!(
+                    check($prefer, $alignment, $source),
+                    (errors, $fixed.to_owned())
+                );
+            }
+        };
+    }
+
+    test_commentstyle!(
+        either_no_violation,
+        PreferredStyle::Either,
+        true,
+        r"
+        /// @title Test
+        /// @author Test
+        contract Test {
+            /**
+             * @notice A function
+             *     indented
+             *
+             */
+            function foo() external {}
+
+            /** @notice A single line */
+            function bar() external {}
+        }
+        ",
+        vec![],
+        r"
+        /// @title Test
+        /// @author Test
+        contract Test {
+            /**
+             * @notice A function
+             *     indented
+             *
+             */
+            function foo() external {}
+
+            /** @notice A single line */
+            function bar() external {}
+        }
+        "
+    );
+
+    test_commentstyle!(
+        either_mixed_violation,
+        PreferredStyle::Either,
+        false,
+        r"
+        /// @title Test
+        /**
+         * @author Test
+         */
+        contract Test {
+            /** @notice A function */
+            /// @dev Some details
+            function foo() external {}
+        }
+        ",
+        vec![
+            ViolationError::MixedDocCommentStyles,
+            ViolationError::MixedDocCommentStyles
+        ],
+        r"
+        /// @title Test
+        /// @author Test
+        contract Test {
+            /** @notice A function */
+            /** @dev Some details */
+            function foo() external {}
+        }
+        "
+    );
+
+    test_commentstyle!(
+        triple_slash_violation,
+        PreferredStyle::TripleSlash,
+        true,
+        r"
+        /// @title Test
+        contract Test {
+            /**
+             * @notice A function
+             *
+             * @dev Some details
+             *   on two lines
+             */
+            function foo() external {}
+
+            /** @notice Followed by code */ function bar() external {}
+        }
+        ",
+        vec![
+            ViolationError::DocCommentStyle(DocStyle::Line),
+            ViolationError::DocCommentStyle(DocStyle::Line)
+        ],
+        r"
+        /// @title Test
+        contract Test {
+            /// @notice A function
+            ///
+            /// @dev Some details
+            ///   on two lines
+            function foo() external {}
+
+            /** @notice Followed by code */ function bar() external {}
+        }
+        "
+    );
+
+    test_commentstyle!(
+        block_violation,
+        PreferredStyle::Block,
+        true,
+        r"
+        /// @title Test
+        contract Test {
+            /// @notice A function
+            /// @dev Some details
+            function foo() external {}
+
+            /// @notice A single line
+            function bar() external {}
+        }
+        ",
+        vec![ViolationError::DocCommentStyle(DocStyle::Block)],
+        r"
+        /// @title Test
+        contract Test {
+            /**
+             * @notice A function
+             * @dev Some details
+             */
+            function foo() external {}
+
+            /// @notice A single line
+            function bar() external {}
+        }
+        "
+    );
+
+    test_commentstyle!(
+        misaligned_violation,
+        PreferredStyle::Either,
+        true,
+        r"
+        /**
+        * @title Test
+          */
+        contract Test {
+            /**
+               @notice A function
+             *@dev Some details
+            */
+            function foo() external {}
+        }
+        ",
+        vec![
+            ViolationError::MisalignedDocComment,
+            ViolationError::MisalignedDocComment
+        ],
+        r"
+        /**
+         * @title Test
+         */
+        contract Test {
+            /**
+             * @notice A function
+             * @dev Some details
+             */
+            function foo() external {}
+        }
+        "
+    );
+
+    test_commentstyle!(
+        misaligned_disabled_no_violation,
+        PreferredStyle::Either,
+        false,
+        r"
+        contract Test {
+            /**
+               @notice A function
+            */
+            function foo() external {}
+        }
+        ",
+        vec![],
+        r"
+        contract Test {
+            /**
+               @notice A function
+            */
+            function foo() external {}
+        }
+        "
+    );
+}
@@ -0,0 +1,257 @@
+use std::any::{Any, TypeId};
+
+use crate::{
+    parser::{Comment, CommentTag, CommentsRef, ParseItem},
+    rules::{context::RuleContext, violation_error::ViolationError, DynRule, Violation},
+};
+
+/// This rule requires that the natspec tags of an item have some content.
+///
+/// An empty `@notice`, or a `@param` with only the name of the parameter, satisfies the rules
+/// requiring the tag while documenting nothing. Only the configured tags are checked.
+pub struct EmptyTag {
+    tags: Vec<CommentTag>,
+}
+
+impl EmptyTag {
+    /// The name of the rule.
+    pub const NAME: &'static str = "EmptyTag";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Natspec tags must have some content, after the name for parameters and return variables.";
+
+    /// Create the rule, checking the given tags.
+    #[must_use]
+    pub const fn new(tags: Vec<CommentTag>) -> Self {
+        Self { tags }
+    }
+
+    /// Check the comments of the item for empty tags.
+    #[must_use]
+    pub fn check(&self, item: &ParseItem) -> Option<Violation> {
+        item.comments
+            .iter()
+            .filter(|comment| self.tags.contains(&comment.tag))
+            .find_map(|comment| empty_comment_error(item, comment))
+            .map(|error| Violation::new(Self::NAME, Self::DESCRIPTION, error, item.source.loc()))
+    }
+}
+
+impl DynRule for EmptyTag {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(item)
+    }
+}
+
+/// Returns the error if the comment has no content.
+/// `@param` comments start with the name of the parameter, and so do `@return` comments of named
+/// return variables.
+fn empty_comment_error(item: &ParseItem, comment: &Comment) -> Option<ViolationError> {
+    let value = comment.value.trim();
+    if value.is_empty() {
+        return Some(ViolationError::EmptyComment(comment.tag.clone()));
+    }
+
+    let (name, description) = value.split_once(char::is_whitespace).unwrap_or((value, ""));
+    let is_named = match comment.tag {
+        CommentTag::Param => true,
+        CommentTag::Return => item.as_function().is_some_and(|func| {
+            func.returns.iter().any(|(_, param)| {
+                param
+                    .as_ref()
+                    .and_then(|param| param.name.as_ref())
+                    .is_some_and(|id| id.name == name)
+            })
+        }),
+        _ => false,
+    };
+
+    (is_named && description.trim().is_empty()).then(|| ViolationError::EmptyCommentFor {
+        tag: comment.tag.clone(),
+        name: name.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmptyTag, Violation};
+    use crate::{
+        parser::{CommentTag, Parser},
+        rules::violation_error::ViolationError,
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    fn rule() -> EmptyTag {
+        EmptyTag::new(vec![
+            CommentTag::Notice,
+            CommentTag::Dev,
+            CommentTag::Param,
+            CommentTag::Return,
+        ])
+    }
+
+    macro_rules! test_emptytag {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let item = parent.children.first().unwrap();
+
+                let expected = $expected(item.source.loc());
+
+                assert_eq!(rule().check(item), expected);
+            }
+        };
+    }
+
+    test_emptytag!(
+        documented_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @param value Some value
+            /// @return total The total
+            /// @return The other total
+            function test(uint256 value) public returns (uint256 total, uint256) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_emptytag!(
+        multiline_no_violation,
+        r"
+        contract Test {
+            /**
+             * @notice Some function
+             * @param value
+             *   Some value
+             */
+            function test(uint256 value) public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_emptytag!(
+        empty_notice_violation,
+        r"
+        contract Test {
+            /// @notice
+            function test() public {}
+        }
+        ",
+        |loc| Some(Violation::new(
+            EmptyTag::NAME,
+            EmptyTag::DESCRIPTION,
+            ViolationError::EmptyComment(CommentTag::Notice),
+            loc
+        ))
+    );
+
+    test_emptytag!(
+        param_name_only_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @param amount
+            function test(uint256 amount) public {}
+        }
+        ",
+        |loc| Some(Violation::new(
+            EmptyTag::NAME,
+            EmptyTag::DESCRIPTION,
+            ViolationError::EmptyCommentFor {
+                tag: CommentTag::Param,
+                name: "amount".to_owned()
+            },
+            loc
+        ))
+    );
+
+    test_emptytag!(
+        empty_return_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @return
+            function test() public returns (uint256) {}
+        }
+        ",
+        |loc| Some(Violation::new(
+            EmptyTag::NAME,
+            EmptyTag::DESCRIPTION,
+            ViolationError::EmptyComment(CommentTag::Return),
+            loc
+        ))
+    );
+
+    test_emptytag!(
+        named_return_name_only_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @return total
+            function test() public returns (uint256 total) {}
+        }
+        ",
+        |loc| Some(Violation::new(
+            EmptyTag::NAME,
+            EmptyTag::DESCRIPTION,
+            ViolationError::EmptyCommentFor {
+                tag: CommentTag::Return,
+                name: "total".to_owned()
+            },
+            loc
+        ))
+    );
+
+    test_emptytag!(
+        unnamed_return_single_word_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @return Total
+            function test() public returns (uint256) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_emptytag!(
+        unchecked_tag_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @author
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+}
@@ -0,0 +1,222 @@
+use std::any::{Any, TypeId};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    rules::{context::RuleContext, violation_error::ViolationError, DynRule},
+};
+
+use super::super::Violation;
+
+/// This rule requires that all the natspec tags of an item are well formed.
+///
+/// A space after the `@` (`@ dev`) or a custom tag without a name (`@custom`, `@custom:`) makes
+/// the compiler and the other rules ignore the tag. Each malformed tag of the item is reported.
+pub struct MalformedTag;
+
+impl MalformedTag {
+    /// The name of the rule.
+    pub const NAME: &'static str = "MalformedTag";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Natspec tags must directly follow the `@`, and custom tags must be named `custom:<name>`.";
+
+    /// Check the tags of the item, reporting each malformed one.
+    #[must_use]
+    pub fn check(item: &ParseItem) -> Vec<Violation> {
+        item.comments
+            .invalid_tags()
+            .iter()
+            .filter(|invalid| super::is_malformed(invalid))
+            .map(|invalid| {
+                Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    ViolationError::MalformedTag {
+                        tag: invalid.tag.clone(),
+                        suggestion: super::suggest_malformed(invalid),
+                    },
+                    invalid.loc,
+                )
+            })
+            .collect()
+    }
+}
+
+impl DynRule for MalformedTag {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(
+        &self,
+        ctx: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        self.check_all(ctx, item, comments).into_iter().next()
+    }
+
+    fn check_all(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Vec<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        Self::check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MalformedTag, Violation};
+    use crate::{
+        parser::Parser,
+        rules::{macros::text_loc, violation_error::ViolationError},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_malformedtag {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let item = parent.children.first().unwrap();
+
+                let expected: Vec<Violation> = $expected($source);
+
+                assert_eq!(MalformedTag::check(item), expected);
+            }
+        };
+    }
+
+    test_malformedtag!(
+        well_formed_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @custom:security Some custom tag
+            function test() public {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_malformedtag!(
+        space_after_at_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @ dev Some details
+            function test() public {}
+        }
+        ",
+        |src| vec![Violation::new(
+            MalformedTag::NAME,
+            MalformedTag::DESCRIPTION,
+            ViolationError::MalformedTag {
+                tag: String::new(),
+                suggestion: Some("dev".to_owned()),
+            },
+            text_loc(src, "@ dev").with_end(src.find("@ dev").unwrap() + 1)
+        )]
+    );
+
+    test_malformedtag!(
+        custom_without_colon_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @custom security Some custom tag
+            function test() public {}
+        }
+        ",
+        |src| vec![Violation::new(
+            MalformedTag::NAME,
+            MalformedTag::DESCRIPTION,
+            ViolationError::MalformedTag {
+                tag: "custom".to_owned(),
+                suggestion: Some("custom:security".to_owned()),
+            },
+            text_loc(src, "@custom")
+        )]
+    );
+
+    test_malformedtag!(
+        custom_without_name_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @custom: Some custom tag
+            function test() public {}
+        }
+        ",
+        |src| vec![Violation::new(
+            MalformedTag::NAME,
+            MalformedTag::DESCRIPTION,
+            ViolationError::MalformedTag {
+                tag: "custom:".to_owned(),
+                suggestion: None,
+            },
+            text_loc(src, "@custom:")
+        )]
+    );
+
+    test_malformedtag!(
+        multiple_violations,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @custom security Some custom tag
+            /// @custom: Some other custom tag
+            function test() public {}
+        }
+        ",
+        |src| vec![
+            Violation::new(
+                MalformedTag::NAME,
+                MalformedTag::DESCRIPTION,
+                ViolationError::MalformedTag {
+                    tag: "custom".to_owned(),
+                    suggestion: Some("custom:security".to_owned()),
+                },
+                text_loc(src, "@custom")
+            ),
+            Violation::new(
+                MalformedTag::NAME,
+                MalformedTag::DESCRIPTION,
+                ViolationError::MalformedTag {
+                    tag: "custom:".to_owned(),
+                    suggestion: None,
+                },
+                text_loc(src, "@custom:")
+            ),
+        ]
+    );
+
+    test_malformedtag!(
+        unknown_tag_no_violation,
+        r"
+        contract Test {
+            /// @notic Some function
+            function test() public {}
+        }
+        ",
+        |_| vec![]
+    );
+}
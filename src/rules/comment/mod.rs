@@ -0,0 +1,279 @@
+//! This module defines the rules for the natspec comments themselves, regardless of the item
+//! they document.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    iter,
+};
+
+use solang_parser::pt::CodeLocation;
+
+use crate::{
+    parser::{CommentTag, InvalidTag, ParseItem},
+    project::SourceFile,
+};
+
+use super::{scope::RuleFilter, FileRuleAdapter};
+
+mod comment_style;
+mod empty_tag;
+mod malformed_tag;
+mod natspec_in_plain_comment;
+mod orphan_doc_comment;
+mod tag_order;
+mod unknown_tag;
+
+// The rules are exported here:
+pub use comment_style::{CommentStyle, CommentStyleConfig, PreferredStyle};
+pub use empty_tag::EmptyTag;
+pub use malformed_tag::MalformedTag;
+pub use natspec_in_plain_comment::NatspecInPlainComment;
+pub use orphan_doc_comment::OrphanDocComment;
+pub use tag_order::TagOrder;
+pub use unknown_tag::UnknownTag;
+
+/// The natspec tags recognized by the compiler, besides the `custom:` tags.
+const KNOWN_TAGS: [&str; 7] = [
+    "title",
+    "author",
+    "notice",
+    "dev",
+    "param",
+    "return",
+    "inheritdoc",
+];
+
+/// Configuration for comment rules in the linter.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[allow(missing_docs, clippy::struct_excessive_bools)]
+pub struct CommentRulesConfig {
+    #[serde(default = "CommentRulesConfig::default_comment_style")]
+    pub comment_style: bool,
+    #[serde(default = "CommentRulesConfig::default_empty_tag")]
+    pub empty_tag: bool,
+    #[serde(default = "CommentRulesConfig::default_malformed_tag")]
+    pub malformed_tag: bool,
+    #[serde(default = "CommentRulesConfig::default_natspec_in_plain_comment")]
+    pub natspec_in_plain_comment: bool,
+    #[serde(default = "CommentRulesConfig::default_orphan_doc_comment")]
+    pub orphan_doc_comment: bool,
+    #[serde(default = "CommentRulesConfig::default_tag_order")]
+    pub tag_order: bool,
+    #[serde(default = "CommentRulesConfig::default_unknown_tag")]
+    pub unknown_tag: bool,
+    /// The tags checked by `empty_tag`, e.g. `notice` or `custom:security`.
+    /// Unknown tags are rejected when the configuration is loaded.
+    #[serde(
+        default = "CommentRulesConfig::default_non_empty_tags",
+        deserialize_with = "super::tag_name::deserialize_all"
+    )]
+    pub non_empty_tags: Vec<String>,
+    /// The order checked by `tag_order`, where `custom` stands for all the `@custom:` tags.
+    #[serde(default = "CommentRulesConfig::default_ordered_tags")]
+    pub ordered_tags: Vec<String>,
+    /// The style checked by `comment_style`.
+    #[serde(default)]
+    pub style: CommentStyleConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub filters: BTreeMap<String, RuleFilter>,
+}
+
+impl Default for CommentRulesConfig {
+    fn default() -> Self {
+        Self {
+            comment_style: true,
+            empty_tag: true,
+            malformed_tag: true,
+            natspec_in_plain_comment: true,
+            orphan_doc_comment: true,
+            tag_order: false,
+            unknown_tag: true,
+            non_empty_tags: ["title", "author", "notice", "dev", "param", "return"]
+                .map(str::to_owned)
+                .to_vec(),
+            ordered_tags: [
+                "title",
+                "author",
+                "notice",
+                "dev",
+                "param",
+                "return",
+                "custom",
+                "inheritdoc",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+            style: CommentStyleConfig::default(),
+            filters: BTreeMap::new(),
+        }
+    }
+}
+
+impl CommentRulesConfig {
+    /// Returns an iterator over the rules in the configuration.
+    /// Rules with an entry in `filters` only apply to the matching items.
+    #[must_use]
+    pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
+        let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
+
+        if self.comment_style {
+            rules.push(Box::new(CommentStyle::new(&self.style)));
+        }
+        if self.empty_tag {
+            let tags = self
+                .non_empty_tags
+                .iter()
+                .filter_map(|tag| CommentTag::from_str(tag))
+                .collect();
+            rules.push(Box::new(EmptyTag::new(tags)));
+        }
+        if self.malformed_tag {
+            rules.push(Box::new(MalformedTag));
+        }
+        if self.natspec_in_plain_comment {
+            rules.push(Box::new(FileRuleAdapter::<NatspecInPlainComment>::new()));
+        }
+        if self.orphan_doc_comment {
+            rules.push(Box::new(FileRuleAdapter::<OrphanDocComment>::new()));
+        }
+        if self.tag_order {
+            rules.push(Box::new(TagOrder::new(self.ordered_tags.clone())));
+        }
+        if self.unknown_tag {
+            rules.push(Box::new(UnknownTag));
+        }
+
+        super::scope::apply_filters(rules, &self.filters)
+    }
+
+    fn default_comment_style() -> bool {
+        Self::default().comment_style
+    }
+    fn default_empty_tag() -> bool {
+        Self::default().empty_tag
+    }
+    fn default_malformed_tag() -> bool {
+        Self::default().malformed_tag
+    }
+    fn default_natspec_in_plain_comment() -> bool {
+        Self::default().natspec_in_plain_comment
+    }
+    fn default_orphan_doc_comment() -> bool {
+        Self::default().orphan_doc_comment
+    }
+    fn default_tag_order() -> bool {
+        Self::default().tag_order
+    }
+    fn default_unknown_tag() -> bool {
+        Self::default().unknown_tag
+    }
+    fn default_non_empty_tags() -> Vec<String> {
+        Self::default().non_empty_tags
+    }
+    fn default_ordered_tags() -> Vec<String> {
+        Self::default().ordered_tags
+    }
+}
+
+/// Returns `true` if the tag is not written like a tag, as opposed to a tag with an unknown name:
+/// a space after the `@` (`@ dev`), or a custom tag without a colon or name (`@custom`,
+/// `@custom:`).
+fn is_malformed(invalid: &InvalidTag) -> bool {
+    invalid.tag.is_empty() || invalid.tag == "custom" || invalid.tag == "custom:"
+}
+
+/// Returns the correction of a malformed tag, e.g. `dev` for `@ dev`, or `custom:name` for
+/// `@custom name`.
+fn suggest_malformed(invalid: &InvalidTag) -> Option<String> {
+    let first_word = invalid.value.split_whitespace().next()?;
+    match invalid.tag.as_str() {
+        "" => suggest_unknown(first_word),
+        "custom" => Some(format!("custom:{first_word}")),
+        _ => None,
+    }
+}
+
+/// Returns the known tag closest to an unknown tag, if it is close enough to be a typo.
+fn suggest_unknown(tag: &str) -> Option<String> {
+    closest(tag, KNOWN_TAGS).map(str::to_owned)
+}
+
+/// Returns the candidate closest to a misspelled name, if it is close enough to be a typo.
+pub(super) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let lowercase = name.to_lowercase();
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, edit_distance(&lowercase, candidate)))
+        .filter(|(candidate, distance)| *distance <= 2 && *distance < candidate.len())
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Returns the start offsets of the items and their children.
+fn item_starts(items: &[ParseItem]) -> HashSet<usize> {
+    items
+        .iter()
+        .flat_map(|item| iter::once(item.source.loc().start()).chain(item_starts(&item.children)))
+        .collect()
+}
+
+/// Returns the offset of the code following the `index`-th comment of the file, skipping
+/// whitespace and the other comments.
+fn next_code(file: &SourceFile, index: usize) -> usize {
+    let mut offset = file.comments[index].loc().end();
+    let mut next_comments = file.comments[index + 1..].iter().peekable();
+
+    loop {
+        offset += file.content[offset..]
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(file.content.len() - offset);
+
+        match next_comments.next_if(|comment| comment.loc().start() == offset) {
+            Some(comment) => offset = comment.loc().end(),
+            None => return offset,
+        }
+    }
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, suggest_unknown};
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("notic", "notice"), 1);
+        assert_eq!(edit_distance("params", "param"), 1);
+        assert_eq!(edit_distance("retrun", "return"), 2);
+        assert_eq!(edit_distance("", "dev"), 3);
+    }
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(suggest_unknown("notic"), Some("notice".to_owned()));
+        assert_eq!(suggest_unknown("Notice"), Some("notice".to_owned()));
+        assert_eq!(suggest_unknown("params"), Some("param".to_owned()));
+        assert_eq!(suggest_unknown("returns"), Some("return".to_owned()));
+        assert_eq!(suggest_unknown("inheritDoc"), Some("inheritdoc".to_owned()));
+        assert_eq!(suggest_unknown("deprecated"), None);
+    }
+}
@@ -0,0 +1,379 @@
+use solang_parser::pt::{CodeLocation, Comment as SolangComment};
+
+use crate::{
+    project::SourceFile,
+    rules::{
+        context::RuleContext,
+        fix::{Edit, Fix},
+        violation_error::ViolationError,
+        FileRule, Violation,
+    },
+};
+
+use super::KNOWN_TAGS;
+
+/// This rule reports natspec tags written in plain `//` or `/* */` comments right above a
+/// declaration.
+///
+/// The compiler only reads natspec from `///` and `/** */` comments, so `// @notice ...` is
+/// silently ignored and the declaration is reported as undocumented by the other rules. The fix
+/// turns the comments holding the tags, and the untagged lines continuing them, into doc comments.
+/// Tool directives like `// slither-disable-next-line` stay plain comments.
+pub struct NatspecInPlainComment;
+
+impl FileRule for NatspecInPlainComment {
+    const NAME: &'static str = "NatspecInPlainComment";
+    const DESCRIPTION: &'static str = "Natspec comments must start with `///` or `/**`.";
+
+    fn check(_: &RuleContext, file: &SourceFile) -> Vec<Violation> {
+        let starts = super::item_starts(&file.items);
+
+        plain_comment_groups(file)
+            .into_iter()
+            .filter(|group| starts.contains(&super::next_code(file, group.end - 1)))
+            .filter_map(|group| {
+                let first = group
+                    .clone()
+                    .find(|i| has_natspec_tag(&file.comments[*i]))?;
+                let edits = natspec_comments(&file.comments[first..group.end])
+                    .map(doc_comment_edit)
+                    .collect();
+
+                Some(
+                    Violation::new(
+                        Self::NAME,
+                        Self::DESCRIPTION,
+                        ViolationError::NatspecInPlainComment,
+                        file.comments[first].loc(),
+                    )
+                    .with_fix(Fix::new(edits)),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Returns the index ranges of the runs of plain comments separated only by whitespace.
+fn plain_comment_groups(file: &SourceFile) -> Vec<std::ops::Range<usize>> {
+    let mut groups: Vec<std::ops::Range<usize>> = vec![];
+    for (i, comment) in file.comments.iter().enumerate() {
+        if !is_plain(comment) {
+            continue;
+        }
+        match groups.last_mut() {
+            Some(group)
+                if group.end == i
+                    && file.content[file.comments[i - 1].loc().end()..comment.loc().start()]
+                        .trim()
+                        .is_empty() =>
+            {
+                group.end += 1;
+            }
+            _ => groups.push(i..i + 1),
+        }
+    }
+    groups
+}
+
+/// Returns `true` for the `//` and `/* */` comments, excluding the `////` and `/***` separators
+/// that cannot be turned into doc comments.
+fn is_plain(comment: &SolangComment) -> bool {
+    match comment {
+        SolangComment::Line(_, text) => !text.starts_with("///"),
+        SolangComment::Block(_, text) => !text.starts_with("/**"),
+        SolangComment::DocLine(..) | SolangComment::DocBlock(..) => false,
+    }
+}
+
+/// Returns `true` if a line of the comment starts with a known natspec tag, e.g. `@notice` or
+/// `@custom:security`.
+fn has_natspec_tag(comment: &SolangComment) -> bool {
+    comment
+        .value()
+        .lines()
+        .any(|line| natspec_tag(line).is_some())
+}
+
+/// Returns `true` if the first line of the comment with some text starts with a known natspec tag.
+fn starts_with_natspec_tag(comment: &SolangComment) -> bool {
+    first_words(comment)
+        .next()
+        .is_some_and(|word| natspec_tag(word).is_some())
+}
+
+/// Returns the known natspec tag the line starts with, if any.
+fn natspec_tag(line: &str) -> Option<&str> {
+    line.trim_start_matches(|c: char| c.is_whitespace() || c == '*' || c == '/')
+        .strip_prefix('@')
+        .and_then(|tag| tag.split_whitespace().next())
+        .filter(|tag| KNOWN_TAGS.contains(tag) || tag.starts_with("custom:"))
+}
+
+/// Returns the words of the first line of the comment with some text, without the comment markers.
+fn first_words(comment: &SolangComment) -> std::str::SplitWhitespace<'_> {
+    comment
+        .value()
+        .lines()
+        .map(|line| line.trim_start_matches(|c: char| c.is_whitespace() || c == '*' || c == '/'))
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or_default()
+        .split_whitespace()
+}
+
+/// Returns `true` for the comments read by tools, e.g. `// natlint-disable-next-line`,
+/// `// slither-disable-next-line reentrancy-eth` or `// forgefmt: disable-next-line`, which must
+/// stay plain comments.
+fn is_tool_directive(comment: &SolangComment) -> bool {
+    let mut words = first_words(comment);
+    let Some(first) = words.next() else {
+        return false;
+    };
+    let is_switch = |word: &str| {
+        ["disable", "enable"].iter().any(|switch| {
+            word.starts_with(&format!("{switch}-")) || word.contains(&format!("-{switch}"))
+        })
+    };
+    first.starts_with("natlint-")
+        || is_switch(first)
+        || (first.ends_with(':') && words.next().is_some_and(is_switch))
+}
+
+/// Returns the comments of a group to turn into doc comments, starting with the first one holding
+/// a tag: the comments starting with a tag, and the untagged comments continuing them. A tool
+/// directive or a comment starting with another `@` annotation ends the continuation.
+fn natspec_comments(comments: &[SolangComment]) -> impl Iterator<Item = &SolangComment> {
+    let mut continued = false;
+    comments
+        .iter()
+        .enumerate()
+        .filter(move |(i, comment)| {
+            continued = if is_tool_directive(comment) {
+                false
+            } else if *i == 0 || starts_with_natspec_tag(comment) {
+                true
+            } else {
+                continued
+                    && !first_words(comment)
+                        .next()
+                        .is_some_and(|word| word.starts_with('@'))
+            };
+            continued
+        })
+        .map(|(_, comment)| comment)
+}
+
+/// Returns the edit turning a plain comment into a doc comment.
+fn doc_comment_edit(comment: &SolangComment) -> Edit {
+    let marker = match comment {
+        SolangComment::Line(..) => "/",
+        _ => "*",
+    };
+    Edit::insert(comment.loc().start() + 2, marker)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{FileRule, NatspecInPlainComment};
+    use crate::{
+        project::SourceFile,
+        rules::{
+            context::RuleContext,
+            fix::{apply_fixes, Fix},
+            violation_error::ViolationError,
+        },
+    };
+
+    /// Returns the start offsets of the reported comments, and the fixed source.
+    fn check(src: &str) -> (Vec<usize>, String) {
+        let file = SourceFile::parse(PathBuf::new(), src.to_owned()).unwrap();
+        let violations = NatspecInPlainComment::check(&RuleContext::from_file(&file), &file);
+        assert!(violations
+            .iter()
+            .all(|violation| violation.error == ViolationError::NatspecInPlainComment));
+
+        let all_fixes = violations
+            .iter()
+            .filter_map(|violation| violation.fix.as_ref())
+            .collect::<Vec<&Fix>>();
+        let (fixed, _) = apply_fixes(src, &all_fixes);
+        let starts = violations
+            .iter()
+            .map(|violation| violation.loc.start())
+            .collect();
+        (starts, fixed)
+    }
+
+    macro_rules! test_natspecinplaincomment {
+        ($name:ident, $source:expr, $reported:expr, $fixed:expr) => {
+            #[test]
+            fn $name() {
+                let reported: Vec<&str> = $reported;
+                let expected = reported
+                    .into_iter()
+                    .map(|comment| $source.find(comment).unwrap())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(check($source), (expected, $fixed.to_owned()));
+            }
+        };
+    }
+
+    test_natspecinplaincomment!(
+        doc_comments_no_violation,
+        r"
+        /// @title Test
+        contract Test {
+            /** @notice A function */
+            function foo() external {}
+        }
+        ",
+        vec![],
+        r"
+        /// @title Test
+        contract Test {
+            /** @notice A function */
+            function foo() external {}
+        }
+        "
+    );
+
+    test_natspecinplaincomment!(
+        plain_comment_without_tag_no_violation,
+        r"
+        // SPDX-License-Identifier: MIT
+        contract Test {
+            // Sends an email to admin@example.com
+            function foo() external {}
+        }
+        ",
+        vec![],
+        r"
+        // SPDX-License-Identifier: MIT
+        contract Test {
+            // Sends an email to admin@example.com
+            function foo() external {}
+        }
+        "
+    );
+
+    test_natspecinplaincomment!(
+        not_above_declaration_no_violation,
+        r"
+        contract Test {
+            function foo() external {
+                // @dev Inside the body
+                uint256 a = 1;
+            }
+        }
+        ",
+        vec![],
+        r"
+        contract Test {
+            function foo() external {
+                // @dev Inside the body
+                uint256 a = 1;
+            }
+        }
+        "
+    );
+
+    test_natspecinplaincomment!(
+        line_comment_violation,
+        r"
+        // @title Test
+        contract Test {
+            // Section header
+
+            // @notice Transfers tokens
+            // to the receiver
+            // natlint-disable-next-line MissingParams
+            // @param to The receiver
+            function foo(address to) external {}
+        }
+        ",
+        vec!["// @title Test", "// @notice Transfers tokens"],
+        r"
+        /// @title Test
+        contract Test {
+            // Section header
+
+            /// @notice Transfers tokens
+            /// to the receiver
+            // natlint-disable-next-line MissingParams
+            /// @param to The receiver
+            function foo(address to) external {}
+        }
+        "
+    );
+
+    test_natspecinplaincomment!(
+        tool_directive_violation,
+        r"
+        contract Test {
+            // @notice Withdraws the balance
+            // slither-disable-next-line reentrancy-eth
+            // forgefmt: disable-next-line
+            // Callers must be trusted
+            // @audit Checked in the last review
+            // to the sender
+            function withdraw() external {}
+        }
+        ",
+        vec!["// @notice Withdraws the balance"],
+        r"
+        contract Test {
+            /// @notice Withdraws the balance
+            // slither-disable-next-line reentrancy-eth
+            // forgefmt: disable-next-line
+            // Callers must be trusted
+            // @audit Checked in the last review
+            // to the sender
+            function withdraw() external {}
+        }
+        "
+    );
+
+    test_natspecinplaincomment!(
+        block_comment_violation,
+        r"
+        contract Test {
+            /*
+             * @notice A variable
+             * @custom:security Read only
+             */
+            uint256 public value;
+        }
+        ",
+        vec!["/*\n"],
+        r"
+        contract Test {
+            /**
+             * @notice A variable
+             * @custom:security Read only
+             */
+            uint256 public value;
+        }
+        "
+    );
+
+    test_natspecinplaincomment!(
+        mixed_with_doc_comment_violation,
+        r"
+        contract Test {
+            // @notice A function
+            /// @dev Some details
+            function foo() external {}
+        }
+        ",
+        vec!["// @notice A function"],
+        r"
+        contract Test {
+            /// @notice A function
+            /// @dev Some details
+            function foo() external {}
+        }
+        "
+    );
+}
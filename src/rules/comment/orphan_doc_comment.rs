@@ -0,0 +1,230 @@
+use solang_parser::{
+    doccomment::parse_doccomments,
+    pt::{CodeLocation, Comment as SolangComment, Loc},
+};
+
+use crate::{
+    parser::{ParseItem, ParseSource},
+    project::SourceFile,
+    rules::{context::RuleContext, violation_error::ViolationError, FileRule, Violation},
+};
+
+/// This rule requires that doc comments are placed right above a documentable declaration.
+///
+/// Doc comments are only attached to the declaration that directly follows them, so the compiler
+/// and the other rules ignore the ones at the end of a contract or inside a function body, and
+/// attach the ones above an `import` or a `using` directive to whatever comes next. Doc comments
+/// of struct fields, enum values and function parameters and returns are allowed, as a common
+/// convention, and so are the `@solidity` annotations of assembly blocks.
+pub struct OrphanDocComment;
+
+impl FileRule for OrphanDocComment {
+    const NAME: &'static str = "OrphanDocComment";
+    const DESCRIPTION: &'static str = "Doc comments must be placed right above a declaration.";
+
+    fn check(_: &RuleContext, file: &SourceFile) -> Vec<Violation> {
+        let starts = super::item_starts(&file.items);
+        let mut lists = vec![];
+        member_lists(&file.items, &mut lists);
+
+        file.comments
+            .iter()
+            .enumerate()
+            .filter(|(_, comment)| {
+                matches!(
+                    comment,
+                    SolangComment::DocLine(..) | SolangComment::DocBlock(..)
+                )
+            })
+            .filter(|(i, comment)| {
+                !starts.contains(&super::next_code(file, *i))
+                    && !is_solidity_annotation(comment)
+                    && !lists.iter().any(|loc: &Loc| {
+                        loc.start() <= comment.loc().start() && comment.loc().end() <= loc.end()
+                    })
+            })
+            .map(|(_, comment)| {
+                Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    ViolationError::OrphanDocComment,
+                    comment.loc(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Collects the locations of the structs and enums, and of the parameter and return lists of the
+/// functions, whose members may be documented, e.g. `/** @custom:name amount */ uint256`.
+fn member_lists(items: &[ParseItem], lists: &mut Vec<Loc>) {
+    for item in items {
+        match &item.source {
+            ParseSource::Struct(_) | ParseSource::Enum(_) => lists.push(item.source.loc()),
+            ParseSource::Function(func) => {
+                if let Some((last, _)) = func.returns.last().or_else(|| func.params.last()) {
+                    lists.push(func.loc.with_end(last.end()));
+                }
+            }
+            _ => {}
+        }
+        member_lists(&item.children, lists);
+    }
+}
+
+/// Returns `true` if the doc comment only holds `@solidity` tags, e.g. the
+/// `/// @solidity memory-safe-assembly` annotation of an assembly block.
+fn is_solidity_annotation(comment: &SolangComment) -> bool {
+    let docs = parse_doccomments(std::slice::from_ref(comment), 0, usize::MAX);
+    !docs.is_empty()
+        && docs
+            .iter()
+            .flat_map(|doc| doc.comments())
+            .all(|tag| tag.tag == "solidity")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{FileRule, OrphanDocComment, Violation};
+    use crate::{
+        project::SourceFile,
+        rules::{context::RuleContext, violation_error::ViolationError},
+    };
+    use solang_parser::pt::Loc;
+
+    /// Returns the location of the line comment starting with `comment` in `src`.
+    fn comment_loc(src: &str, comment: &str) -> Loc {
+        let start = src.find(comment).unwrap();
+        let end = start + src[start..].find('\n').unwrap();
+        Loc::File(0, start, end)
+    }
+
+    macro_rules! test_orphandoccomment {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let file = SourceFile::parse(PathBuf::new(), $source.to_owned()).unwrap();
+                let expected: Vec<&str> = $expected;
+                let expected = expected
+                    .into_iter()
+                    .map(|comment| {
+                        Violation::new(
+                            OrphanDocComment::NAME,
+                            OrphanDocComment::DESCRIPTION,
+                            ViolationError::OrphanDocComment,
+                            comment_loc($source, comment),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    OrphanDocComment::check(&RuleContext::from_file(&file), &file),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_orphandoccomment!(
+        attached_no_violation,
+        r"
+        /// @title Test
+        /**
+         * @author Test
+         */
+        contract Test {
+            /// @notice A variable
+            uint256 public value;
+
+            /// @notice A function
+            // natlint-disable-next-line MissingParams
+            function foo(uint256 a) external {}
+
+            /// @notice A struct
+            struct Point {
+                /// @dev The x coordinate
+                uint256 x;
+            }
+        }
+        ",
+        vec![]
+    );
+
+    test_orphandoccomment!(
+        function_parameters_no_violation,
+        r"
+        contract Test {
+            /// @notice A function
+            /// @custom:param The amount
+            /// @return Whether it worked
+            function foo(
+                /** @custom:name amount */ uint256,
+                /// @custom:name recipient
+                address
+            ) external returns (/** @custom:name success */ bool) {}
+        }
+        ",
+        vec![]
+    );
+
+    test_orphandoccomment!(
+        assembly_annotation_no_violation,
+        r"
+        contract Test {
+            function foo() external {
+                /// @solidity memory-safe-assembly
+                assembly {}
+            }
+        }
+        ",
+        vec![]
+    );
+
+    test_orphandoccomment!(
+        end_of_contract_violation,
+        r"
+        contract Test {
+            function foo() external {}
+
+            /// @notice Nothing follows
+        }
+        ",
+        vec!["/// @notice Nothing follows"]
+    );
+
+    test_orphandoccomment!(
+        function_body_violation,
+        r"
+        contract Test {
+            function foo() external {
+                /// @notice Inside the body
+                uint256 a = 1;
+            }
+
+            function bar() external {}
+        }
+        ",
+        vec!["/// @notice Inside the body"]
+    );
+
+    test_orphandoccomment!(
+        directive_violation,
+        r"
+        /// @notice Above an import
+        import './Foo.sol';
+
+        contract Test {
+            /// @notice Above a using directive
+            using Foo for uint256;
+
+            function foo() external {}
+        }
+        ",
+        vec![
+            "/// @notice Above an import",
+            "/// @notice Above a using directive"
+        ]
+    );
+}
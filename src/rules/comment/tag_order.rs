@@ -0,0 +1,411 @@
+use std::{
+    any::{Any, TypeId},
+    ops::Range,
+};
+
+use solang_parser::pt::Loc;
+
+use crate::{
+    parser::{CommentsRef, DocStyle, ParseItem, SourceComment},
+    rules::{
+        context::RuleContext,
+        fix::{Edit, Fix},
+        violation_error::ViolationError,
+        DynRule, Violation,
+    },
+};
+
+/// This rule requires that the natspec tags of an item appear in the configured order.
+///
+/// A tag spans its line and the following lines without a tag, and `custom` in the order stands
+/// for all the `@custom:` tags. Tags missing from the order stay after the tag they follow. The
+/// fix reorders the tags when the docs are a run of `///` lines or a single `/** */` block whose
+/// tags start on their own lines.
+pub struct TagOrder {
+    order: Vec<String>,
+}
+
+/// A line of a doc comment.
+struct DocLine<'a> {
+    /// The span of the line, from the first character after the indentation.
+    span: Range<usize>,
+    /// The content of the line, without the comment delimiters.
+    content: &'a str,
+}
+
+/// A tag and the indices of its lines.
+struct TagGroup<'a> {
+    tag: &'a str,
+    lines: Range<usize>,
+}
+
+impl TagOrder {
+    /// The name of the rule.
+    pub const NAME: &'static str = "TagOrder";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str = "Natspec tags must appear in the configured order.";
+
+    /// Create the rule, checking the given order of tags.
+    #[must_use]
+    pub const fn new(order: Vec<String>) -> Self {
+        Self { order }
+    }
+
+    /// Check the order of the tags of the item.
+    #[must_use]
+    pub fn check(&self, ctx: &RuleContext, item: &ParseItem) -> Option<Violation> {
+        let text = ctx.source()?;
+        let sources = item.comments.source_comments();
+        let (lines, movable) = doc_lines(text, sources);
+        let groups = tag_groups(&lines);
+
+        // Tags missing from the order keep the rank of the tag they follow
+        let mut ranks = Vec::with_capacity(groups.len());
+        let mut last_rank = 0;
+        let mut highest: Option<(usize, &str)> = None;
+        let mut out_of_order = None;
+        for group in &groups {
+            let rank = self.rank(group.tag).unwrap_or(last_rank);
+            match highest {
+                Some((highest_rank, highest_tag)) if rank < highest_rank => {
+                    out_of_order.get_or_insert((group, highest_tag));
+                }
+                Some((highest_rank, _)) if rank == highest_rank => {}
+                _ => highest = Some((rank, group.tag)),
+            }
+            ranks.push(rank);
+            last_rank = rank;
+        }
+
+        let (group, before) = out_of_order?;
+        let span = &lines[group.lines.start].span;
+        let violation = Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            ViolationError::TagOutOfOrder {
+                tag: group.tag.to_owned(),
+                before: before.to_owned(),
+            },
+            Loc::File(0, span.start, span.end),
+        );
+
+        if !movable {
+            return Some(violation);
+        }
+        Some(violation.with_fix(reorder(text, &lines, &groups, &ranks)))
+    }
+
+    /// Returns the position of the tag in the order, if it is listed.
+    fn rank(&self, tag: &str) -> Option<usize> {
+        let position = |name: &str| self.order.iter().position(|ordered| ordered == name);
+        position(tag).or_else(|| {
+            tag.starts_with("custom:")
+                .then(|| position("custom"))
+                .flatten()
+        })
+    }
+}
+
+impl DynRule for TagOrder {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, ctx: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(ctx, item)
+    }
+}
+
+/// Returns the lines of the doc comments holding some content, and whether they can be moved:
+/// the doc comments are a run of `///` lines, or a single `/** */` block with its delimiters on
+/// their own lines.
+fn doc_lines<'a>(text: &'a str, sources: &[SourceComment]) -> (Vec<DocLine<'a>>, bool) {
+    let mut lines = vec![];
+    let mut movable = match sources {
+        [] => false,
+        [source] if source.style == DocStyle::Block => true,
+        _ => sources.windows(2).all(|pair| {
+            pair.iter().all(|source| source.style == DocStyle::Line)
+                && text[pair[0].loc.end()..pair[1].loc.start()]
+                    .trim()
+                    .is_empty()
+        }),
+    };
+
+    for source in sources {
+        let (start, end) = (source.loc.start(), source.loc.end());
+        if source.style == DocStyle::Line {
+            lines.push(DocLine {
+                span: start..end,
+                content: text[start + 3..end].trim(),
+            });
+            continue;
+        }
+
+        let block = &text[start..end];
+        let count = block.split('\n').count();
+        let mut offset = start;
+        for (i, line) in block.split('\n').enumerate() {
+            let span_start = offset + (line.len() - line.trim_start().len());
+            let span = span_start..offset + line.trim_end().len();
+            offset += line.len() + 1;
+
+            let mut content = line.trim();
+            if i == 0 {
+                content = content.trim_start_matches("/**");
+            }
+            if i == count - 1 {
+                content = content.trim_end_matches("*/");
+            }
+            let content = content.trim_start_matches('*').trim();
+
+            if content.is_empty() {
+                continue;
+            }
+            // The delimiters must be on their own lines to move the lines in between
+            if i == 0 || i == count - 1 {
+                movable = false;
+            }
+            lines.push(DocLine { span, content });
+        }
+    }
+
+    (lines, movable)
+}
+
+/// Groups the lines by tag. Lines before the first tag are an implicit `@notice`.
+fn tag_groups<'a>(lines: &[DocLine<'a>]) -> Vec<TagGroup<'a>> {
+    let mut groups: Vec<TagGroup> = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        match line.content.strip_prefix('@') {
+            Some(tag) => groups.push(TagGroup {
+                tag: tag.split_whitespace().next().unwrap_or_default(),
+                lines: i..i + 1,
+            }),
+            None => match groups.last_mut() {
+                Some(group) => group.lines.end = i + 1,
+                None => groups.push(TagGroup {
+                    tag: "notice",
+                    lines: i..i + 1,
+                }),
+            },
+        }
+    }
+    groups
+}
+
+/// Returns the fix sorting the groups by rank, keeping the text between the lines in place.
+fn reorder(text: &str, lines: &[DocLine], groups: &[TagGroup], ranks: &[usize]) -> Fix {
+    let mut order = (0..groups.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| ranks[*i]);
+
+    let sorted = order
+        .into_iter()
+        .flat_map(|i| groups[i].lines.clone())
+        .map(|i| &text[lines[i].span.clone()]);
+    let gaps = lines
+        .windows(2)
+        .map(|pair| &text[pair[0].span.end..pair[1].span.start]);
+
+    let mut replacement = String::new();
+    for (line, gap) in sorted.zip(gaps.map(Some).chain([None])) {
+        replacement.push_str(line);
+        replacement.push_str(gap.unwrap_or_default());
+    }
+
+    let (start, end) = (lines[0].span.start, lines[lines.len() - 1].span.end);
+    Fix::new(vec![Edit::replace(start, end, replacement)])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::TagOrder;
+    use crate::{
+        project::SourceFile,
+        rules::{context::RuleContext, fix::apply_fixes, violation_error::ViolationError},
+    };
+
+    /// Returns the errors of the children of the first contract, whether they have a fix, and
+    /// the fixed source.
+    fn check(src: &str) -> (Vec<(ViolationError, bool)>, String) {
+        let file = SourceFile::parse(PathBuf::new(), src.to_owned()).unwrap();
+        let rule = TagOrder::new(
+            [
+                "title",
+                "author",
+                "notice",
+                "dev",
+                "param",
+                "return",
+                "custom",
+                "inheritdoc",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+        );
+        let ctx = RuleContext::from_file(&file);
+
+        let violations = file.items[0]
+            .children
+            .iter()
+            .filter_map(|item| rule.check(&ctx, item))
+            .collect::<Vec<_>>();
+
+        let fixes = violations
+            .iter()
+            .filter_map(|violation| violation.fix.as_ref())
+            .collect::<Vec<_>>();
+        let (output, _) = apply_fixes(src, &fixes);
+        let errors = violations
+            .into_iter()
+            .map(|violation| (violation.error, violation.fix.is_some()))
+            .collect();
+        (errors, output)
+    }
+
+    fn out_of_order(tag: &str, before: &str, fixed: bool) -> (ViolationError, bool) {
+        (
+            ViolationError::TagOutOfOrder {
+                tag: tag.to_owned(),
+                before: before.to_owned(),
+            },
+            fixed,
+        )
+    }
+
+    macro_rules! test_tagorder {
+        ($name:ident, $source:expr, $errors:expr, $fixed:expr) => {
+            #[test]
+            fn $name() {
+                let errors: Vec<(ViolationError, bool)> = $errors;
+                assert_eq!(check($source), (errors, $fixed.to_owned()));
+            }
+        };
+    }
+
+    test_tagorder!(
+        ordered_no_violation,
+        r"
+        contract Test {
+            /// Implicit notice
+            /// @dev Some details
+            /// @param a The first
+            /// @param b The second
+            /// @return The result
+            /// @custom:security Checked
+            /// @custom:audit Done
+            function foo(uint256 a, uint256 b) external returns (uint256) {}
+        }
+        ",
+        vec![],
+        r"
+        contract Test {
+            /// Implicit notice
+            /// @dev Some details
+            /// @param a The first
+            /// @param b The second
+            /// @return The result
+            /// @custom:security Checked
+            /// @custom:audit Done
+            function foo(uint256 a, uint256 b) external returns (uint256) {}
+        }
+        "
+    );
+
+    test_tagorder!(
+        triple_slash_violation,
+        r"
+        contract Test {
+            /// @dev Some details
+            ///      on two lines
+            /// @notice A function
+            /// @unknown Kept after the notice
+            function foo() external {}
+        }
+        ",
+        vec![out_of_order("notice", "dev", true)],
+        r"
+        contract Test {
+            /// @notice A function
+            /// @unknown Kept after the notice
+            /// @dev Some details
+            ///      on two lines
+            function foo() external {}
+        }
+        "
+    );
+
+    test_tagorder!(
+        block_violation,
+        r"
+        contract Test {
+            /**
+             * @return The result
+             * @param a The first
+             *
+             * @custom:security Checked
+             * @notice A function
+             */
+            function foo(uint256 a) external returns (uint256) {}
+        }
+        ",
+        vec![out_of_order("param", "return", true)],
+        r"
+        contract Test {
+            /**
+             * @notice A function
+             * @param a The first
+             *
+             * @return The result
+             * @custom:security Checked
+             */
+            function foo(uint256 a) external returns (uint256) {}
+        }
+        "
+    );
+
+    test_tagorder!(
+        not_movable_violation,
+        r"
+        contract Test {
+            /** @dev Some details
+             *  @notice A function */
+            function foo() external {}
+
+            /// @dev Some details
+            // A plain comment
+            /// @notice A function
+            function bar() external {}
+        }
+        ",
+        vec![
+            out_of_order("notice", "dev", false),
+            out_of_order("notice", "dev", false)
+        ],
+        r"
+        contract Test {
+            /** @dev Some details
+             *  @notice A function */
+            function foo() external {}
+
+            /// @dev Some details
+            // A plain comment
+            /// @notice A function
+            function bar() external {}
+        }
+        "
+    );
+}
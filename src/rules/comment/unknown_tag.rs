@@ -0,0 +1,248 @@
+use std::any::{Any, TypeId};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    rules::{context::RuleContext, violation_error::ViolationError, DynRule},
+};
+
+use super::super::Violation;
+
+/// This rule requires that all the natspec tags of an item are known, e.g. not misspelled.
+///
+/// The compiler and the other rules ignore unknown tags, so a misspelled `@notic` would otherwise
+/// only be reported as a missing notice. Each unknown tag of the item is reported.
+pub struct UnknownTag;
+
+impl UnknownTag {
+    /// The name of the rule.
+    pub const NAME: &'static str = "UnknownTag";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Natspec tags must be known tags, or custom tags starting with `custom:`.";
+
+    /// Check the tags of the item, reporting each unknown one.
+    #[must_use]
+    pub fn check(item: &ParseItem) -> Vec<Violation> {
+        item.comments
+            .invalid_tags()
+            .iter()
+            .filter(|invalid| !super::is_malformed(invalid))
+            .map(|invalid| {
+                Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    ViolationError::UnknownTag {
+                        tag: invalid.tag.clone(),
+                        suggestion: super::suggest_unknown(&invalid.tag),
+                    },
+                    invalid.loc,
+                )
+            })
+            .collect()
+    }
+}
+
+impl DynRule for UnknownTag {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(
+        &self,
+        ctx: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        self.check_all(ctx, item, comments).into_iter().next()
+    }
+
+    fn check_all(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Vec<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        Self::check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnknownTag, Violation};
+    use crate::{
+        parser::{ParseItem, Parser},
+        rules::{macros::text_loc, violation_error::ViolationError},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_unknowntag {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let item = parent.children.first().unwrap();
+
+                let expected: Vec<Violation> = $expected($source);
+
+                assert_eq!(UnknownTag::check(item), expected);
+            }
+        };
+    }
+
+    test_unknowntag!(
+        known_tags_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            /// @dev Some details
+            /// @custom:security Some custom tag
+            function test() public {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_unknowntag!(
+        misspelled_tag_violation,
+        r"
+        contract Test {
+            /// @notic Some function
+            function test() public {}
+        }
+        ",
+        |src| vec![Violation::new(
+            UnknownTag::NAME,
+            UnknownTag::DESCRIPTION,
+            ViolationError::UnknownTag {
+                tag: "notic".to_owned(),
+                suggestion: Some("notice".to_owned()),
+            },
+            text_loc(src, "@notic")
+        )]
+    );
+
+    test_unknowntag!(
+        block_comment_violation,
+        r"
+        contract Test {
+            /**
+             * @notice Some function
+             * @params value Some value
+             */
+            function test(uint256 value) public {}
+        }
+        ",
+        |src| vec![Violation::new(
+            UnknownTag::NAME,
+            UnknownTag::DESCRIPTION,
+            ViolationError::UnknownTag {
+                tag: "params".to_owned(),
+                suggestion: Some("param".to_owned()),
+            },
+            text_loc(src, "@params")
+        )]
+    );
+
+    test_unknowntag!(
+        no_suggestion_violation,
+        r"
+        contract Test {
+            /// @notice Some event
+            /// @deprecated Use another event
+            event Test();
+        }
+        ",
+        |src| vec![Violation::new(
+            UnknownTag::NAME,
+            UnknownTag::DESCRIPTION,
+            ViolationError::UnknownTag {
+                tag: "deprecated".to_owned(),
+                suggestion: None,
+            },
+            text_loc(src, "@deprecated")
+        )]
+    );
+
+    test_unknowntag!(
+        multiple_violations,
+        r"
+        contract Test {
+            /// @notic Some function
+            /// @params value Some value
+            /// @ dev Some details
+            function test(uint256 value) public {}
+        }
+        ",
+        |src| vec![
+            Violation::new(
+                UnknownTag::NAME,
+                UnknownTag::DESCRIPTION,
+                ViolationError::UnknownTag {
+                    tag: "notic".to_owned(),
+                    suggestion: Some("notice".to_owned()),
+                },
+                text_loc(src, "@notic")
+            ),
+            Violation::new(
+                UnknownTag::NAME,
+                UnknownTag::DESCRIPTION,
+                ViolationError::UnknownTag {
+                    tag: "params".to_owned(),
+                    suggestion: Some("param".to_owned()),
+                },
+                text_loc(src, "@params")
+            ),
+        ]
+    );
+
+    test_unknowntag!(
+        malformed_tag_no_violation,
+        r"
+        contract Test {
+            /// @ notice Some function
+            function test() public {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    #[test]
+    fn contract_violation() {
+        let source = r"
+        /// @title Test
+        /// @auther Someone
+        contract Test {}
+        ";
+        let src = parse_source(source);
+        let item: &ParseItem = src.items_ref().first().unwrap();
+
+        assert_eq!(
+            UnknownTag::check(item),
+            vec![Violation::new(
+                UnknownTag::NAME,
+                UnknownTag::DESCRIPTION,
+                ViolationError::UnknownTag {
+                    tag: "auther".to_owned(),
+                    suggestion: Some("author".to_owned()),
+                },
+                text_loc(source, "@auther")
+            )]
+        );
+    }
+}
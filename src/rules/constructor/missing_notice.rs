@@ -0,0 +1,70 @@
+use solang_parser::pt::FunctionDefinition;
+
+crate::missing_comment_rule!(
+    MissingNotice,
+    FunctionDefinition,
+    Notice,
+    "Constructors must have a notice comment.",
+    super::is_constructor
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionDefinition, MissingNotice};
+    use crate::{
+        generate_missing_comment_test_cases,
+        parser::{CommentTag, CommentsRef, Parser},
+        rules::{violation_error::ViolationError, Rule, Violation},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_missingnotice {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    MissingNotice::check(Some(parent), func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    generate_missing_comment_test_cases!(
+        Notice,
+        test_missingnotice,
+        MissingNotice,
+        r"
+            constructor(address owner) {}
+        ",
+        "@notice",
+        FunctionDefinition
+    );
+
+    test_missingnotice!(
+        function_no_violation,
+        r"
+        contract Test {
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+}
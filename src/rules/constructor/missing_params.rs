@@ -0,0 +1,162 @@
+use solang_parser::pt::FunctionDefinition;
+
+use crate::parser::{CommentTag, CommentsRef, ParseItem};
+
+use super::super::{function::check_parameter_docs, Rule, Violation};
+
+/// This rule requires that all constructors have their parameters documented.
+pub struct MissingParams;
+
+impl Rule for MissingParams {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "MissingParams";
+    const DESCRIPTION: &'static str = "Constructors must have their parameters documented.";
+
+    fn check(
+        _: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        if !super::is_constructor(func) {
+            return None;
+        }
+
+        // Constructor must have a parameter comment for each parameter
+        check_parameter_docs(&func.params, &CommentTag::Param, comments, func.loc)
+            .map(|(error, loc)| Violation::new(Self::NAME, Self::DESCRIPTION, error, loc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommentTag, CommentsRef, FunctionDefinition, MissingParams, Rule, Violation};
+    use crate::{parser::Parser, rules::violation_error::ViolationError};
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_missingparams {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    MissingParams::check(Some(parent), func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_missingparams!(
+        no_params_no_violation,
+        r"
+        contract Test {
+            constructor() {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        no_violation,
+        r"
+        contract Test {
+            /// @param owner The initial owner
+            /// @param fee The initial fee
+            constructor(address owner, uint256 fee) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        function_no_violation,
+        r"
+        contract Test {
+            function test(uint256 a) public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        missing_violation,
+        r"
+        contract Test {
+            /// @notice Deploys the contract
+            constructor(address owner) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::MissingComment(CommentTag::Param),
+            func.loc
+        ))
+    );
+
+    test_missingparams!(
+        inheritdoc_violation,
+        r"
+        contract Test {
+            /// @inheritdoc Base
+            constructor(address owner) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::MissingComment(CommentTag::Param),
+            func.loc
+        ))
+    );
+
+    test_missingparams!(
+        too_many_comments_violation,
+        r"
+        contract Test {
+            /// @param owner The initial owner
+            /// @param fee The initial fee
+            constructor(address owner) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::TooManyComments(CommentTag::Param),
+            func.loc
+        ))
+    );
+
+    test_missingparams!(
+        name_not_found_violation,
+        r"
+        contract Test {
+            /// @param owner The initial owner
+            /// @param admin The initial fee
+            constructor(address owner, uint256 fee) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::missing_comment_for(CommentTag::Param, "fee"),
+            func.params[1].0
+        ))
+    );
+}
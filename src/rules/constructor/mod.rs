@@ -0,0 +1,72 @@
+//! This module defines the rules for constructors in the natlint linter.
+//!
+//! Constructors are parsed as function definitions, so these rules share the `FunctionDefinition`
+//! target with the function rules and only act on `FunctionTy::Constructor`.
+
+use solang_parser::pt::{FunctionDefinition, FunctionTy};
+
+mod missing_notice;
+mod missing_params;
+mod no_return;
+
+// The rules are exported here:
+pub use missing_notice::MissingNotice;
+pub use missing_params::MissingParams;
+pub use no_return::NoReturn;
+
+/// Configuration for constructor rules in the linter.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[allow(missing_docs, clippy::struct_excessive_bools)]
+pub struct ConstructorRulesConfig {
+    #[serde(default = "ConstructorRulesConfig::default_missing_notice")]
+    pub missing_notice: bool,
+    #[serde(default = "ConstructorRulesConfig::default_missing_params")]
+    pub missing_params: bool,
+    #[serde(default = "ConstructorRulesConfig::default_no_return")]
+    pub no_return: bool,
+}
+
+impl Default for ConstructorRulesConfig {
+    fn default() -> Self {
+        Self {
+            missing_notice: true,
+            missing_params: true,
+            no_return: true,
+        }
+    }
+}
+
+impl ConstructorRulesConfig {
+    /// Returns an iterator over the rules in the configuration.
+    #[must_use]
+    pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
+        let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
+
+        if self.missing_notice {
+            rules.push(Box::new(MissingNotice));
+        }
+        if self.missing_params {
+            rules.push(Box::new(MissingParams));
+        }
+        if self.no_return {
+            rules.push(Box::new(NoReturn));
+        }
+
+        rules
+    }
+
+    fn default_missing_notice() -> bool {
+        Self::default().missing_notice
+    }
+    fn default_missing_params() -> bool {
+        Self::default().missing_params
+    }
+    fn default_no_return() -> bool {
+        Self::default().no_return
+    }
+}
+
+/// Returns `true` if the function definition is a constructor.
+pub(crate) const fn is_constructor(func: &FunctionDefinition) -> bool {
+    matches!(func.ty, FunctionTy::Constructor)
+}
@@ -0,0 +1,416 @@
+//! This module defines the context a rule is checked in.
+//!
+//! Most rules only need the item and its parent, but some (e.g. validating `@inheritdoc` targets)
+//! need to know about the rest of the file, or the rest of the project.
+
+use std::{collections::HashSet, path::Path, ptr};
+
+use solang_parser::pt::{Base, ContractDefinition, Import};
+
+use crate::{
+    parser::ParseItem,
+    project::{signature, ContractRef, Project, SourceFile},
+};
+
+/// The context a rule is checked in.
+#[derive(Clone, Copy, Debug)]
+pub struct RuleContext<'a> {
+    /// The parent item, e.g. the contract a function is declared in.
+    pub parent: Option<&'a ParseItem>,
+    /// The top-level items of the file.
+    pub items: &'a [ParseItem],
+    /// The import directives of the file.
+    pub imports: &'a [Import],
+    /// The file, if the rule is checked by the linter.
+    pub file: Option<&'a SourceFile>,
+    /// The project the file belongs to, if it is linted as part of one.
+    pub project: Option<&'a Project>,
+    /// The path of the file, if known.
+    pub path: Option<&'a Path>,
+}
+
+impl<'a> RuleContext<'a> {
+    /// Create a new rule context.
+    #[must_use]
+    pub const fn new(
+        parent: Option<&'a ParseItem>,
+        items: &'a [ParseItem],
+        imports: &'a [Import],
+    ) -> Self {
+        Self {
+            parent,
+            items,
+            imports,
+            file: None,
+            project: None,
+            path: None,
+        }
+    }
+
+    /// Create a rule context for the top-level items of a file.
+    #[must_use]
+    pub fn from_file(file: &'a SourceFile) -> Self {
+        Self {
+            file: Some(file),
+            path: (!file.path.as_os_str().is_empty()).then_some(file.path.as_path()),
+            ..Self::new(None, &file.items, &file.imports)
+        }
+    }
+
+    /// Set the project the file at `path` is linted in.
+    #[must_use]
+    pub const fn with_project(mut self, project: &'a Project, path: &'a Path) -> Self {
+        self.project = Some(project);
+        self.path = Some(path);
+        self
+    }
+
+    /// Returns the parent contract, resolved in the project.
+    #[must_use]
+    pub fn parent_contract(&self) -> Option<ContractRef<'a>> {
+        let name = &self.parent?.as_contract()?.name.as_ref()?.name;
+        self.project?.resolve_contract(self.path?, name)
+    }
+
+    /// Create a rule context that only knows about the parent item.
+    #[must_use]
+    pub const fn from_parent(parent: Option<&'a ParseItem>) -> Self {
+        Self::new(parent, &[], &[])
+    }
+
+    /// Returns the ancestors of the item, outermost first, i.e. ending with the parent.
+    /// Only the parent is known if it is not one of the items of the file.
+    #[must_use]
+    pub fn ancestors(&self) -> Vec<&'a ParseItem> {
+        let Some(parent) = self.parent else {
+            return vec![];
+        };
+        let mut ancestors = vec![];
+        if find_path(self.items, parent, &mut ancestors) {
+            ancestors
+        } else {
+            vec![parent]
+        }
+    }
+
+    /// Returns the items declared next to the item, including itself: the members of the parent,
+    /// or the top-level items of the file.
+    #[must_use]
+    pub fn siblings(&self) -> &'a [ParseItem] {
+        self.parent.map_or(self.items, |parent| &parent.children)
+    }
+
+    /// Returns the base list of the parent contract, e.g. `[IFoo, Bar]` for `contract Foo is IFoo,
+    /// Bar`.
+    #[must_use]
+    pub fn parent_bases(&self) -> &'a [Base] {
+        self.parent
+            .and_then(ParseItem::as_contract)
+            .map_or(&[], |contract| &contract.base)
+    }
+
+    /// Returns the raw source text of the file, if known.
+    #[must_use]
+    pub fn source(&self) -> Option<&'a str> {
+        self.file.map(|file| file.content.as_str())
+    }
+
+    /// Returns the value of a pragma of the file, e.g. `^0.8.20` for `solidity`.
+    #[must_use]
+    pub fn pragma(&self, name: &str) -> Option<&'a str> {
+        self.file?.pragma(name)
+    }
+
+    /// Returns the version requirement of the `pragma solidity` directive, e.g. `^0.8.20`.
+    #[must_use]
+    pub fn solidity_version(&self) -> Option<&'a str> {
+        self.pragma("solidity")
+    }
+
+    /// Returns the license expression of the `SPDX-License-Identifier` comment of the file.
+    #[must_use]
+    pub fn spdx_license(&self) -> Option<&'a str> {
+        self.file?.spdx_license()
+    }
+
+    /// Returns the contract with the given name if it is declared in the file.
+    #[must_use]
+    pub fn contract(&self, name: &str) -> Option<&'a ContractDefinition> {
+        self.contract_item(name)?.as_contract()
+    }
+
+    /// Returns the item of the contract with the given name if it is declared in the file.
+    fn contract_item(&self, name: &str) -> Option<&'a ParseItem> {
+        self.items.iter().find(|item| {
+            item.as_contract()
+                .and_then(|contract| contract.name.as_ref())
+                .is_some_and(|id| id.name == name)
+        })
+    }
+
+    /// Returns the member of `base` that an `@inheritdoc base` comment of a member with the given
+    /// signature refers to. The base is looked up in the project when available, and in the file
+    /// otherwise.
+    #[must_use]
+    pub fn inheritdoc_target(&self, base: &str, signature: &str) -> Option<&'a ParseItem> {
+        if let (Some(project), Some(parent)) = (self.project, self.parent_contract()) {
+            return project
+                .inheritdoc_target(&parent, base, signature)
+                .map(|(_, member)| member);
+        }
+
+        let contract = self.contract_item(base)?;
+        signature::find_member(contract, signature)
+    }
+
+    /// Returns whether `base` or one of its ancestors declares a member with the selector of
+    /// `signature`. The base is looked up in the project when available, and in the file
+    /// otherwise.
+    /// Returns `None` if it is unknown because the base or some of its ancestors could not be
+    /// resolved.
+    #[must_use]
+    pub fn declares_member(&self, base: &str, signature: &str) -> Option<bool> {
+        if let (Some(project), Some(parent)) = (self.project, self.parent_contract()) {
+            let base = project
+                .ancestors(&parent)
+                .into_iter()
+                .find(|ancestor| ancestor.is_named(base))?
+                .contract?;
+            return project.declares_member(&base, signature);
+        }
+
+        let mut visited = HashSet::new();
+        let mut pending = vec![base.to_owned()];
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+            let contract = self.contract_item(&name)?;
+            if signature::find_member(contract, signature).is_some() {
+                return Some(true);
+            }
+            pending.extend(contract.as_contract()?.base.iter().map(base_name));
+        }
+
+        Some(false)
+    }
+
+    /// Returns `true` if the name may be imported into the file.
+    /// Plain imports (`import "Foo.sol";`) import every symbol, so any name may come from them.
+    #[must_use]
+    pub fn is_imported(&self, name: &str) -> bool {
+        self.imports.iter().any(|import| match import {
+            Import::Plain(..) => true,
+            Import::GlobalSymbol(_, alias, _) => name
+                .split_once('.')
+                .is_some_and(|(unit, _)| unit == alias.name),
+            Import::Rename(_, symbols, _) => symbols
+                .iter()
+                .any(|(symbol, alias)| alias.as_ref().unwrap_or(symbol).name == name),
+        })
+    }
+
+    /// Returns whether the contract inherits from `base`, directly or through the contracts
+    /// declared in the file.
+    /// Returns `None` if it is unknown because the inheritance chain leaves the file.
+    #[must_use]
+    pub fn inherits_from(&self, contract: &ContractDefinition, base: &str) -> Option<bool> {
+        let mut visited = HashSet::new();
+        let mut pending = contract.base.iter().collect::<Vec<_>>();
+        let mut known = true;
+
+        while let Some(next) = pending.pop() {
+            if base_matches(next, base) {
+                return Some(true);
+            }
+
+            let name = base_name(next);
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+            match self.contract(&name) {
+                Some(declared) => pending.extend(declared.base.iter()),
+                None => known = false,
+            }
+        }
+
+        known.then_some(false)
+    }
+
+    /// Returns `true` if `base` is a valid `@inheritdoc` target for an item of the parent contract.
+    /// The inheritance graph of the project is used when available. When the inheritance chain
+    /// cannot be followed, only the direct bases and the ancestors declared in the file are
+    /// accepted, so a name that is merely imported is not mistaken for a base.
+    #[must_use]
+    pub fn is_inheritdoc_base(&self, base: &str) -> bool {
+        let Some(contract) = self.parent.and_then(ParseItem::as_contract) else {
+            return false;
+        };
+
+        if let Some(inherits) = self.parent_contract().and_then(|parent| {
+            self.project
+                .and_then(|project| project.inherits_from(&parent, base))
+        }) {
+            return inherits;
+        }
+
+        self.inherits_from(contract, base).unwrap_or(false)
+    }
+}
+
+/// Pushes the path from `items` to `target` to `path`, returning `true` if found.
+fn find_path<'a>(
+    items: &'a [ParseItem],
+    target: &ParseItem,
+    path: &mut Vec<&'a ParseItem>,
+) -> bool {
+    for item in items {
+        path.push(item);
+        if ptr::eq(item, target) || find_path(&item.children, target, path) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+/// Returns the name of the base, e.g. `IFoo` or `Lib.IFoo`.
+fn base_name(base: &Base) -> String {
+    base.name
+        .identifiers
+        .iter()
+        .map(|id| id.name.as_str())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Returns `true` if `name` refers to the base, either by its full path or its last identifier.
+fn base_matches(base: &Base, name: &str) -> bool {
+    base_name(base) == name
+        || base
+            .name
+            .identifiers
+            .last()
+            .is_some_and(|id| id.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuleContext;
+    use crate::{
+        parser::{ParseItem, Parser},
+        project::SourceFile,
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::{
+        parse,
+        pt::{Import, SourceUnitPart},
+    };
+    use std::path::{Path, PathBuf};
+
+    fn parse_source(src: &str) -> (Vec<ParseItem>, Vec<Import>) {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let imports = source
+            .0
+            .iter()
+            .filter_map(|part| match part {
+                SourceUnitPart::ImportDirective(import) => Some(import.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        (doc.items(), imports)
+    }
+
+    #[test]
+    fn inherits_from() {
+        let (items, imports) = parse_source(
+            r"
+            import {IExternal} from './IExternal.sol';
+
+            interface IFoo {}
+            interface IBar is IFoo {}
+            contract Local is IBar {}
+            contract Remote is IExternal {}
+            ",
+        );
+        let ctx = RuleContext::new(None, &items, &imports);
+
+        let local = ctx.contract("Local").unwrap();
+        assert_eq!(ctx.inherits_from(local, "IBar"), Some(true));
+        assert_eq!(ctx.inherits_from(local, "IFoo"), Some(true));
+        assert_eq!(ctx.inherits_from(local, "IOther"), Some(false));
+
+        let remote = ctx.contract("Remote").unwrap();
+        assert_eq!(ctx.inherits_from(remote, "IExternal"), Some(true));
+        assert_eq!(ctx.inherits_from(remote, "IOther"), None);
+    }
+
+    #[test]
+    fn is_imported() {
+        let (items, imports) = parse_source(
+            r"
+            import {IFoo, IBar as IBaz} from './IFoo.sol';
+            import './Lib.sol' as Lib;
+            ",
+        );
+        let ctx = RuleContext::new(None, &items, &imports);
+
+        assert!(ctx.is_imported("IFoo"));
+        assert!(ctx.is_imported("IBaz"));
+        assert!(!ctx.is_imported("IBar"));
+        assert!(ctx.is_imported("Lib.IQux"));
+        assert!(!ctx.is_imported("IQux"));
+
+        let (items, imports) = parse_source("import './IFoo.sol';");
+        let ctx = RuleContext::new(None, &items, &imports);
+        assert!(ctx.is_imported("IQux"));
+    }
+
+    #[test]
+    fn file_context() {
+        let file = SourceFile::parse(
+            PathBuf::from("src/Foo.sol"),
+            r"
+            // SPDX-License-Identifier: MIT
+            pragma solidity ^0.8.20;
+
+            interface IFoo {}
+            contract Foo is IFoo {
+                function foo() external {}
+                function bar() external {}
+            }
+            "
+            .to_owned(),
+        )
+        .unwrap();
+        let ctx = RuleContext::from_file(&file);
+
+        assert_eq!(ctx.path, Some(Path::new("src/Foo.sol")));
+        assert_eq!(ctx.solidity_version(), Some("^0.8.20"));
+        assert_eq!(ctx.spdx_license(), Some("MIT"));
+        assert!(ctx
+            .source()
+            .is_some_and(|source| source.contains("contract Foo")));
+        assert!(ctx.ancestors().is_empty());
+        assert_eq!(ctx.siblings().len(), 2);
+        assert!(ctx.parent_bases().is_empty());
+
+        let contract = &file.items[1];
+        let ctx = RuleContext {
+            parent: Some(contract),
+            ..ctx
+        };
+        assert_eq!(ctx.ancestors().len(), 1);
+        assert!(std::ptr::eq(ctx.ancestors()[0], contract));
+        assert_eq!(ctx.siblings().len(), 2);
+        assert_eq!(ctx.parent_bases().len(), 1);
+
+        // Without a file, only the parent is known
+        let ctx = RuleContext::from_parent(Some(contract));
+        assert_eq!(ctx.ancestors().len(), 1);
+        assert_eq!(ctx.source(), None);
+        assert_eq!(ctx.solidity_version(), None);
+    }
+}
@@ -12,10 +12,10 @@ crate::no_comment_rule!(
 
 #[cfg(test)]
 mod tests {
-    use super::{ContractDefinition, NoInheritdoc};
+    use super::NoInheritdoc;
     use crate::{
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -38,7 +38,7 @@ mod tests {
                 let comments = CommentsRef::from(&contract_item.comments);
                 let contract = contract_item.as_contract().unwrap();
 
-                let expected = $expected(contract);
+                let expected = $expected(contract, $source);
 
                 assert_eq!(NoInheritdoc::check(None, contract, &comments), expected);
             }
@@ -51,7 +51,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_inheritdoc!(
@@ -61,7 +61,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_inheritdoc!(
@@ -72,7 +72,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_inheritdoc!(
@@ -84,7 +84,7 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_inheritdoc!(
@@ -97,7 +97,7 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_inheritdoc!(
@@ -107,11 +107,11 @@ mod tests {
         interface Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoInheritdoc::NAME,
             NoInheritdoc::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Inheritdoc),
-            sct.loc
+            text_loc(src, "@inheritdoc Base")
         ))
     );
 
@@ -123,11 +123,11 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoInheritdoc::NAME,
             NoInheritdoc::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Inheritdoc),
-            sct.loc
+            text_loc(src, "@inheritdoc Base")
         ))
     );
 
@@ -140,11 +140,11 @@ mod tests {
         library Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoInheritdoc::NAME,
             NoInheritdoc::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Inheritdoc),
-            sct.loc
+            text_loc(src, "@inheritdoc Base")
         ))
     );
 
@@ -158,11 +158,11 @@ mod tests {
         contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoInheritdoc::NAME,
             NoInheritdoc::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Inheritdoc),
-            sct.loc
+            text_loc(src, "@inheritdoc Base")
         ))
     );
 }
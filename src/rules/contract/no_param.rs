@@ -9,10 +9,10 @@ crate::no_comment_rule!(
 
 #[cfg(test)]
 mod tests {
-    use super::{ContractDefinition, NoParam};
+    use super::NoParam;
     use crate::{
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -35,7 +35,7 @@ mod tests {
                 let comments = CommentsRef::from(&contract_item.comments);
                 let contract = contract_item.as_contract().unwrap();
 
-                let expected = $expected(contract);
+                let expected = $expected(contract, $source);
 
                 assert_eq!(NoParam::check(None, contract, &comments), expected);
             }
@@ -48,7 +48,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_param!(
@@ -58,7 +58,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_param!(
@@ -69,7 +69,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_param!(
@@ -81,7 +81,7 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_param!(
@@ -94,7 +94,7 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_param!(
@@ -104,11 +104,11 @@ mod tests {
         interface Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoParam::NAME,
             NoParam::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Param),
-            sct.loc
+            text_loc(src, "@param Some param")
         ))
     );
 
@@ -120,11 +120,11 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoParam::NAME,
             NoParam::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Param),
-            sct.loc
+            text_loc(src, "@param Some param")
         ))
     );
 
@@ -137,11 +137,11 @@ mod tests {
         library Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoParam::NAME,
             NoParam::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Param),
-            sct.loc
+            text_loc(src, "@param Some param")
         ))
     );
 
@@ -155,11 +155,11 @@ mod tests {
         contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoParam::NAME,
             NoParam::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Param),
-            sct.loc
+            text_loc(src, "@param Some param")
         ))
     );
 }
@@ -9,10 +9,10 @@ crate::no_comment_rule!(
 
 #[cfg(test)]
 mod tests {
-    use super::{ContractDefinition, NoReturn};
+    use super::NoReturn;
     use crate::{
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -35,7 +35,7 @@ mod tests {
                 let comments = CommentsRef::from(&contract_item.comments);
                 let contract = contract_item.as_contract().unwrap();
 
-                let expected = $expected(contract);
+                let expected = $expected(contract, $source);
 
                 assert_eq!(NoReturn::check(None, contract, &comments), expected);
             }
@@ -48,7 +48,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_return!(
@@ -58,7 +58,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_return!(
@@ -69,7 +69,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_return!(
@@ -81,7 +81,7 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_return!(
@@ -94,7 +94,7 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_no_return!(
@@ -104,11 +104,11 @@ mod tests {
         interface Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoReturn::NAME,
             NoReturn::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Return),
-            sct.loc
+            text_loc(src, "@return Some return")
         ))
     );
 
@@ -120,11 +120,11 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoReturn::NAME,
             NoReturn::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Return),
-            sct.loc
+            text_loc(src, "@return Some return")
         ))
     );
 
@@ -137,11 +137,11 @@ mod tests {
         library Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoReturn::NAME,
             NoReturn::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Return),
-            sct.loc
+            text_loc(src, "@return Some return")
         ))
     );
 
@@ -155,11 +155,11 @@ mod tests {
         contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             NoReturn::NAME,
             NoReturn::DESCRIPTION,
             ViolationError::CommentNotAllowed(CommentTag::Return),
-            sct.loc
+            text_loc(src, "@return Some return")
         ))
     );
 }
@@ -9,10 +9,10 @@ crate::too_many_comments_rule!(
 
 #[cfg(test)]
 mod tests {
-    use super::{ContractDefinition, TooManyNotice};
+    use super::TooManyNotice;
     use crate::{
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -35,7 +35,7 @@ mod tests {
                 let comments = CommentsRef::from(&contract_item.comments);
                 let contract = contract_item.as_contract().unwrap();
 
-                let expected = $expected(contract);
+                let expected = $expected(contract, $source);
 
                 assert_eq!(TooManyNotice::check(None, contract, &comments), expected);
             }
@@ -48,7 +48,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
@@ -58,7 +58,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
@@ -69,7 +69,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
@@ -81,7 +81,7 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
@@ -94,7 +94,7 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
@@ -104,7 +104,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
@@ -116,22 +116,22 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_notice!(
         multi_violation,
         r"
         /// @notice Some notice
-        /// @notice Some notice
+        /// @notice Another notice
         abstract contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             TooManyNotice::NAME,
             TooManyNotice::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Notice),
-            sct.loc
+            text_loc(src, "@notice Another notice")
         ))
     );
 
@@ -140,16 +140,16 @@ mod tests {
         r"
         /**
          * @notice Some notice
-         * @notice Some notice
+         * @notice Another notice
          */
         contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             TooManyNotice::NAME,
             TooManyNotice::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Notice),
-            sct.loc
+            text_loc(src, "@notice Another notice")
         ))
     );
 }
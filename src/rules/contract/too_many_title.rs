@@ -9,10 +9,10 @@ crate::too_many_comments_rule!(
 
 #[cfg(test)]
 mod tests {
-    use super::{ContractDefinition, TooManyTitle};
+    use super::TooManyTitle;
     use crate::{
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -35,7 +35,7 @@ mod tests {
                 let comments = CommentsRef::from(&contract_item.comments);
                 let contract = contract_item.as_contract().unwrap();
 
-                let expected = $expected(contract);
+                let expected = $expected(contract, $source);
 
                 assert_eq!(TooManyTitle::check(None, contract, &comments), expected);
             }
@@ -48,7 +48,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
@@ -58,7 +58,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
@@ -69,7 +69,7 @@ mod tests {
         contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
@@ -81,7 +81,7 @@ mod tests {
         abstract contract Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
@@ -94,7 +94,7 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
@@ -104,7 +104,7 @@ mod tests {
         interface Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
@@ -116,22 +116,22 @@ mod tests {
         library Test {
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_too_many_title!(
         multi_violation,
         r"
         /// @title Some title
-        /// @title Some title
+        /// @title Another title
         abstract contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             TooManyTitle::NAME,
             TooManyTitle::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Title),
-            sct.loc
+            text_loc(src, "@title Another title")
         ))
     );
 
@@ -140,16 +140,16 @@ mod tests {
         r"
         /**
          * @title Some title
-         * @title Some title
+         * @title Another title
          */
         contract Test {
         }
         ",
-        |sct: &ContractDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             TooManyTitle::NAME,
             TooManyTitle::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Title),
-            sct.loc
+            text_loc(src, "@title Another title")
         ))
     );
 }
@@ -0,0 +1,395 @@
+//! This module defines the declarative rules of the `[[rules.custom]]` configuration section.
+//!
+//! Each entry requires a number of comments with a tag on the items in its scope, like the rules
+//! defined with [`missing_comment_rule`](crate::missing_comment_rule),
+//! [`no_comment_rule`](crate::no_comment_rule) and
+//! [`too_many_comments_rule`](crate::too_many_comments_rule), under a name of its own.
+//!
+//! ```toml
+//! [[rules.custom]]
+//! name = "MissingExternalDev"
+//! description = "External functions must have a dev comment."
+//! items = ["function"]
+//! visibility = ["external"]
+//! kinds = ["contract"]
+//! tag = "dev"
+//! at_least = 1
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    collections::BTreeSet,
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{CommentTag, CommentsRef, ParseItem};
+
+use super::{
+    context::RuleContext,
+    scope::{ContractKind, ItemKind, Mutability, Scope, ScopedRule, Visibility},
+    violation_error::ViolationError,
+    DynRule, Violation,
+};
+
+/// The `[rules]` configuration section.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CustomRulesConfig {
+    /// The declarative rules, in `[[rules.custom]]` entries.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<CustomRuleConfig>,
+}
+
+impl CustomRulesConfig {
+    /// Returns the rules of the entries, each restricted to its scope.
+    /// The tags are checked when the configuration is loaded, so entries built with an invalid
+    /// tag are ignored, since they cannot match any comment.
+    #[must_use]
+    pub fn rule_set(&self) -> Vec<Box<dyn DynRule>> {
+        self.custom
+            .iter()
+            .filter_map(|config| {
+                let rule = CustomRule::new(config)?;
+                Some(Box::new(ScopedRule::new(Box::new(rule), config.scope())) as Box<dyn DynRule>)
+            })
+            .collect()
+    }
+}
+
+/// An entry of the `[[rules.custom]]` configuration section.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CustomRuleConfig {
+    /// The name of the rule, as reported and used in disable comments.
+    pub name: String,
+    /// A description of the rule.
+    pub description: String,
+    /// The tag counted, e.g. `dev` or `custom:security`.
+    /// Unknown tags are rejected when the configuration is loaded.
+    #[serde(deserialize_with = "super::tag_name::deserialize")]
+    pub tag: String,
+    /// If set, the minimum number of comments with the tag, e.g. `1` for a required tag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at_least: Option<usize>,
+    /// If set, the maximum number of comments with the tag, e.g. `0` for a forbidden tag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at_most: Option<usize>,
+    /// If set, the rule only applies to these kinds of items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ItemKind>>,
+    /// If set, the rule only applies to items in these contract kinds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kinds: Option<Vec<ContractKind>>,
+    /// If set, the rule only applies to functions and variables with these visibilities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Vec<Visibility>>,
+    /// If set, the rule only applies to functions and variables with these mutabilities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutability: Option<Vec<Mutability>>,
+}
+
+impl CustomRuleConfig {
+    /// Returns the items the rule applies to.
+    fn scope(&self) -> Scope {
+        Scope {
+            kinds: self.kinds.clone(),
+            items: self.items.clone(),
+            visibility: self.visibility.clone(),
+            mutability: self.mutability.clone(),
+            ..Scope::default()
+        }
+    }
+}
+
+/// A rule defined in the `[[rules.custom]]` configuration section.
+pub struct CustomRule {
+    name: &'static str,
+    description: &'static str,
+    tag: CommentTag,
+    at_least: Option<usize>,
+    at_most: Option<usize>,
+}
+
+impl CustomRule {
+    /// Create the rule of an entry, or `None` if its tag is invalid.
+    ///
+    /// Violations hold static rule names, so the name and description of the entry are interned:
+    /// they are leaked the first time they are seen, and reused by the rules built afterwards.
+    #[must_use]
+    pub fn new(config: &CustomRuleConfig) -> Option<Self> {
+        Some(Self {
+            name: intern(&config.name),
+            description: intern(&config.description),
+            tag: CommentTag::from_str(&config.tag)?,
+            at_least: config.at_least,
+            at_most: config.at_most,
+        })
+    }
+
+    /// Check the number of comments with the tag on the item.
+    #[must_use]
+    pub fn check(&self, item: &ParseItem) -> Option<Violation> {
+        let count = CommentsRef::from(&item.comments)
+            .include_tag(self.tag.clone())
+            .len();
+
+        let error = match (self.at_least, self.at_most) {
+            (Some(min), _) if count < min => {
+                if count == 0 {
+                    ViolationError::MissingComment(self.tag.clone())
+                } else {
+                    ViolationError::TooFewComments {
+                        tag: self.tag.clone(),
+                        min,
+                    }
+                }
+            }
+            (_, Some(max)) if count > max => {
+                if max == 0 {
+                    ViolationError::CommentNotAllowed(self.tag.clone())
+                } else {
+                    ViolationError::TooManyComments(self.tag.clone())
+                }
+            }
+            _ => return None,
+        };
+
+        Some(Violation::new(
+            self.name,
+            self.description,
+            error,
+            item.source.loc(),
+        ))
+    }
+}
+
+/// The names and descriptions of the custom rules, leaked once to be shared by every rule set.
+static INTERNED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Returns a static copy of the string, leaking it only if it was not interned yet.
+fn intern(value: &str) -> &'static str {
+    let mut interned = INTERNED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_owned().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+impl DynRule for CustomRule {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomRule, CustomRuleConfig, CustomRulesConfig, Violation};
+    use crate::{
+        parser::{CommentTag, CommentsRef, Parser},
+        rules::{
+            context::RuleContext,
+            scope::{ItemKind, Visibility},
+            violation_error::ViolationError,
+        },
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    fn config(tag: &str, at_least: Option<usize>, at_most: Option<usize>) -> CustomRuleConfig {
+        CustomRuleConfig {
+            name: "CustomDev".to_owned(),
+            description: "A custom rule.".to_owned(),
+            tag: tag.to_owned(),
+            at_least,
+            at_most,
+            items: None,
+            kinds: None,
+            visibility: None,
+            mutability: None,
+        }
+    }
+
+    macro_rules! test_customrule {
+        ($name:ident, $config:expr, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+                let item = src.items_ref().first().unwrap();
+
+                let rule = CustomRule::new(&$config).unwrap();
+                let expected = $expected(item.source.loc());
+
+                assert_eq!(rule.check(item), expected);
+            }
+        };
+    }
+
+    test_customrule!(
+        at_least_no_violation,
+        config("dev", Some(1), None),
+        r"
+        /// @dev Some details
+        event Test();
+        ",
+        |_| None
+    );
+
+    test_customrule!(
+        missing_violation,
+        config("dev", Some(1), None),
+        r"
+        /// @notice Some event
+        event Test();
+        ",
+        |loc| Some(Violation::new(
+            "CustomDev",
+            "A custom rule.",
+            ViolationError::MissingComment(CommentTag::Dev),
+            loc
+        ))
+    );
+
+    test_customrule!(
+        too_few_violation,
+        config("custom:example", Some(2), None),
+        r"
+        /// @custom:example One example
+        event Test();
+        ",
+        |loc| Some(Violation::new(
+            "CustomDev",
+            "A custom rule.",
+            ViolationError::TooFewComments {
+                tag: CommentTag::Custom("example".to_owned()),
+                min: 2,
+            },
+            loc
+        ))
+    );
+
+    test_customrule!(
+        at_most_no_violation,
+        config("dev", None, Some(1)),
+        r"
+        /// @dev Some details
+        event Test();
+        ",
+        |_| None
+    );
+
+    test_customrule!(
+        too_many_violation,
+        config("dev", None, Some(1)),
+        r"
+        /// @dev Some details
+        /// @dev Some other details
+        event Test();
+        ",
+        |loc| Some(Violation::new(
+            "CustomDev",
+            "A custom rule.",
+            ViolationError::TooManyComments(CommentTag::Dev),
+            loc
+        ))
+    );
+
+    test_customrule!(
+        not_allowed_violation,
+        config("dev", None, Some(0)),
+        r"
+        /// @dev Some details
+        event Test();
+        ",
+        |loc| Some(Violation::new(
+            "CustomDev",
+            "A custom rule.",
+            ViolationError::CommentNotAllowed(CommentTag::Dev),
+            loc
+        ))
+    );
+
+    #[test]
+    fn invalid_tag() {
+        assert!(CustomRule::new(&config("custom", Some(1), None)).is_none());
+        assert!(CustomRulesConfig {
+            custom: vec![config("notic", Some(1), None)]
+        }
+        .rule_set()
+        .is_empty());
+    }
+
+    #[test]
+    fn interned_names() {
+        let first = CustomRule::new(&config("dev", Some(1), None)).unwrap();
+        let second = CustomRule::new(&config("dev", Some(1), None)).unwrap();
+        assert!(std::ptr::eq(first.name, second.name));
+        assert!(std::ptr::eq(first.description, second.description));
+    }
+
+    #[test]
+    fn scoped() {
+        let src = parse_source(
+            r"
+            contract Test {
+                function external_fn() external {}
+                function internal_fn() internal {}
+                event Test();
+            }
+            ",
+        );
+        let contract = src.items_ref().first().unwrap();
+        let rules = CustomRulesConfig {
+            custom: vec![CustomRuleConfig {
+                items: Some(vec![ItemKind::Function]),
+                visibility: Some(vec![Visibility::External]),
+                ..config("dev", Some(1), None)
+            }],
+        }
+        .rule_set();
+        let ctx = RuleContext::from_parent(Some(contract));
+
+        let violations = contract
+            .children
+            .iter()
+            .filter_map(|child| {
+                rules[0].check_dyn(&ctx, child, &CommentsRef::from(&child.comments))
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            violations,
+            [Violation::new(
+                "CustomDev",
+                "A custom rule.",
+                ViolationError::MissingComment(CommentTag::Dev),
+                contract.children[0].source.loc()
+            )]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::any::{Any, TypeId};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    rules::{context::RuleContext, violation_error::ViolationError, DynRule, Violation},
+};
+
+/// This rule requires that all the custom tags of an item are allowed, which catches misspelled
+/// custom tags.
+pub struct DisallowedCustomTag {
+    allowed: Vec<String>,
+}
+
+impl DisallowedCustomTag {
+    /// The name of the rule.
+    pub const NAME: &'static str = "DisallowedCustomTag";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str = "Custom tags must be one of the allowed custom tags.";
+
+    /// Create the rule, allowing the given custom tags.
+    #[must_use]
+    pub const fn new(allowed: Vec<String>) -> Self {
+        Self { allowed }
+    }
+
+    /// Check the custom tags of the item.
+    #[must_use]
+    pub fn check(&self, item: &ParseItem) -> Option<Violation> {
+        let (name, _) =
+            super::custom_tags(item).find(|(name, _)| !self.allowed.iter().any(|a| a == name))?;
+        let suggestion =
+            super::super::comment::closest(name, self.allowed.iter().map(String::as_str))
+                .map(|allowed| format!("custom:{allowed}"));
+
+        Some(Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            ViolationError::UnknownTag {
+                tag: format!("custom:{name}"),
+                suggestion,
+            },
+            item.source.loc(),
+        ))
+    }
+}
+
+impl DynRule for DisallowedCustomTag {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DisallowedCustomTag, Violation};
+    use crate::{parser::Parser, rules::violation_error::ViolationError};
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_disallowedcustomtag {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+                let item = src.items_ref().first().unwrap();
+
+                let rule = DisallowedCustomTag::new(vec!["security-contact".to_owned()]);
+                let expected = $expected(item.source.loc());
+
+                assert_eq!(rule.check(item), expected);
+            }
+        };
+    }
+
+    test_disallowedcustomtag!(
+        allowed_no_violation,
+        r"
+        /// @title Test
+        /// @custom:security-contact security@example.com
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_disallowedcustomtag!(
+        no_custom_tags_no_violation,
+        r"
+        /// @title Test
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_disallowedcustomtag!(
+        misspelled_violation,
+        r"
+        /// @title Test
+        /// @custom:secuirty-contact security@example.com
+        contract Test {}
+        ",
+        |loc| Some(Violation::new(
+            DisallowedCustomTag::NAME,
+            DisallowedCustomTag::DESCRIPTION,
+            ViolationError::UnknownTag {
+                tag: "custom:secuirty-contact".to_owned(),
+                suggestion: Some("custom:security-contact".to_owned()),
+            },
+            loc
+        ))
+    );
+
+    test_disallowedcustomtag!(
+        not_allowed_violation,
+        r"
+        /// @title Test
+        /// @custom:experimental This is experimental
+        contract Test {}
+        ",
+        |loc| Some(Violation::new(
+            DisallowedCustomTag::NAME,
+            DisallowedCustomTag::DESCRIPTION,
+            ViolationError::UnknownTag {
+                tag: "custom:experimental".to_owned(),
+                suggestion: None,
+            },
+            loc
+        ))
+    );
+}
@@ -0,0 +1,147 @@
+use std::{
+    any::{Any, TypeId},
+    collections::BTreeMap,
+};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    rules::{
+        context::RuleContext, pattern::Pattern, violation_error::ViolationError, DynRule, Violation,
+    },
+};
+
+/// This rule requires that the values of custom tags match the pattern configured for the tag,
+/// e.g. an email address for `@custom:security-contact`.
+pub struct InvalidCustomTagValue {
+    patterns: BTreeMap<String, Pattern>,
+}
+
+impl InvalidCustomTagValue {
+    /// The name of the rule.
+    pub const NAME: &'static str = "InvalidCustomTagValue";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Custom tag values must match the pattern configured for the tag.";
+
+    /// Create the rule, with the patterns of the custom tags.
+    #[must_use]
+    pub const fn new(patterns: BTreeMap<String, Pattern>) -> Self {
+        Self { patterns }
+    }
+
+    /// Check the values of the custom tags of the item.
+    #[must_use]
+    pub fn check(&self, item: &ParseItem) -> Option<Violation> {
+        super::custom_tags(item).find_map(|(name, comment)| {
+            let pattern = self.patterns.get(name)?;
+            (!pattern.is_match(comment.value.trim())).then(|| {
+                Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    ViolationError::InvalidTagValue {
+                        tag: format!("custom:{name}"),
+                        pattern: pattern.to_string(),
+                    },
+                    item.source.loc(),
+                )
+            })
+        })
+    }
+}
+
+impl DynRule for InvalidCustomTagValue {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{InvalidCustomTagValue, Violation};
+    use crate::{
+        parser::Parser,
+        rules::{pattern::Pattern, violation_error::ViolationError},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_invalidcustomtagvalue {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+                let item = src.items_ref().first().unwrap();
+
+                let rule = InvalidCustomTagValue::new(BTreeMap::from([(
+                    "security-contact".to_owned(),
+                    Pattern::new(r"^\S+@\S+$").unwrap(),
+                )]));
+                let expected = $expected(item.source.loc());
+
+                assert_eq!(rule.check(item), expected);
+            }
+        };
+    }
+
+    test_invalidcustomtagvalue!(
+        matching_no_violation,
+        r"
+        /// @title Test
+        /// @custom:security-contact security@example.com
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_invalidcustomtagvalue!(
+        no_pattern_no_violation,
+        r"
+        /// @title Test
+        /// @custom:experimental This is experimental
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_invalidcustomtagvalue!(
+        not_matching_violation,
+        r"
+        /// @title Test
+        /// @custom:security-contact The security team
+        contract Test {}
+        ",
+        |loc| Some(Violation::new(
+            InvalidCustomTagValue::NAME,
+            InvalidCustomTagValue::DESCRIPTION,
+            ViolationError::InvalidTagValue {
+                tag: "custom:security-contact".to_owned(),
+                pattern: r"^\S+@\S+$".to_owned(),
+            },
+            loc
+        ))
+    );
+}
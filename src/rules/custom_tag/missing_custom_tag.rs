@@ -0,0 +1,142 @@
+use std::{
+    any::{Any, TypeId},
+    collections::BTreeMap,
+};
+
+use crate::{
+    parser::{CommentTag, CommentsRef, ParseItem},
+    rules::{
+        context::RuleContext, scope::ItemKind, violation_error::ViolationError, DynRule, Violation,
+    },
+};
+
+/// This rule requires that items have the custom tags required for their kind, e.g. a
+/// `@custom:security-contact` on every contract.
+pub struct MissingCustomTag {
+    required: BTreeMap<ItemKind, Vec<String>>,
+}
+
+impl MissingCustomTag {
+    /// The name of the rule.
+    pub const NAME: &'static str = "MissingCustomTag";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Items must have the custom tags required for their kind.";
+
+    /// Create the rule, requiring the given custom tags per item kind.
+    #[must_use]
+    pub const fn new(required: BTreeMap<ItemKind, Vec<String>>) -> Self {
+        Self { required }
+    }
+
+    /// Check that the item has the custom tags required for its kind.
+    #[must_use]
+    pub fn check(&self, item: &ParseItem) -> Option<Violation> {
+        let kind = ItemKind::of(item.source.node().0)?;
+        let missing = self
+            .required
+            .get(&kind)?
+            .iter()
+            .find(|required| !super::custom_tags(item).any(|(name, _)| name == *required))?;
+
+        Some(Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            ViolationError::MissingComment(CommentTag::Custom(missing.clone())),
+            item.source.loc(),
+        ))
+    }
+}
+
+impl DynRule for MissingCustomTag {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{MissingCustomTag, Violation};
+    use crate::{
+        parser::{CommentTag, Parser},
+        rules::{scope::ItemKind, violation_error::ViolationError},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_missingcustomtag {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+                let item = src.items_ref().first().unwrap();
+
+                let rule = MissingCustomTag::new(BTreeMap::from([
+                    (ItemKind::Contract, vec!["security-contact".to_owned()]),
+                    (ItemKind::Function, vec![]),
+                ]));
+                let expected = $expected(item.source.loc());
+
+                assert_eq!(rule.check(item), expected);
+            }
+        };
+    }
+
+    test_missingcustomtag!(
+        present_no_violation,
+        r"
+        /// @title Test
+        /// @custom:security-contact security@example.com
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_missingcustomtag!(
+        other_kind_no_violation,
+        r"
+        /// @notice Some event
+        event Test();
+        ",
+        |_| None
+    );
+
+    test_missingcustomtag!(
+        missing_violation,
+        r"
+        /// @title Test
+        /// @custom:experimental This is experimental
+        contract Test {}
+        ",
+        |loc| Some(Violation::new(
+            MissingCustomTag::NAME,
+            MissingCustomTag::DESCRIPTION,
+            ViolationError::MissingComment(CommentTag::Custom("security-contact".to_owned())),
+            loc
+        ))
+    );
+}
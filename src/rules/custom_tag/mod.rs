@@ -0,0 +1,72 @@
+//! This module defines the rules for `@custom:` tags in the natlint linter.
+//!
+//! Unlike the other rule tables, the `[custom_tags]` section holds the policy itself: the custom
+//! tags that are allowed, the ones required per item kind, and the patterns their values must
+//! match. Each rule is only enabled when its part of the policy is set.
+
+use std::collections::BTreeMap;
+
+use crate::parser::{Comment, CommentTag, ParseItem};
+
+use super::{pattern::Pattern, scope::ItemKind};
+
+mod disallowed_custom_tag;
+mod invalid_custom_tag_value;
+mod missing_custom_tag;
+
+// The rules are exported here:
+pub use disallowed_custom_tag::DisallowedCustomTag;
+pub use invalid_custom_tag_value::InvalidCustomTagValue;
+pub use missing_custom_tag::MissingCustomTag;
+
+/// Configuration for the custom tags in the linter.
+///
+/// ```toml
+/// [custom_tags]
+/// allowed = ["security-contact", "oz-upgrades-unsafe-allow"]
+/// required = { contract = ["security-contact"] }
+/// patterns = { security-contact = "^\\S+@\\S+$" }
+/// ```
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct CustomTagsConfig {
+    /// If set, the only custom tags allowed, e.g. `security-contact` for
+    /// `@custom:security-contact`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<Vec<String>>,
+    /// The custom tags required on each kind of item.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub required: BTreeMap<ItemKind, Vec<String>>,
+    /// The patterns the values of custom tags must match.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub patterns: BTreeMap<String, Pattern>,
+}
+
+impl CustomTagsConfig {
+    /// Returns an iterator over the rules in the configuration.
+    #[must_use]
+    pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
+        let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
+
+        if let Some(allowed) = &self.allowed {
+            rules.push(Box::new(DisallowedCustomTag::new(allowed.clone())));
+        }
+        if !self.required.is_empty() {
+            rules.push(Box::new(MissingCustomTag::new(self.required.clone())));
+        }
+        if !self.patterns.is_empty() {
+            rules.push(Box::new(InvalidCustomTagValue::new(self.patterns.clone())));
+        }
+
+        rules
+    }
+}
+
+/// Returns the custom tags of the item, along with their names.
+fn custom_tags(item: &ParseItem) -> impl Iterator<Item = (&str, &Comment)> {
+    item.comments
+        .iter()
+        .filter_map(|comment| match &comment.tag {
+            CommentTag::Custom(name) => Some((name.as_str(), comment)),
+            _ => None,
+        })
+}
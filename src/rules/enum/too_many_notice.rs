@@ -13,7 +13,7 @@ mod tests {
     use crate::{
         generate_too_many_comment_test_cases,
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -60,9 +60,7 @@ mod tests {
         EnumDefinition
     );
 
-    test_too_many_notice!(
-        no_tag_violation,
-        r"
+    const NO_TAG_VIOLATION_SOURCE: &str = r"
         contract Test {
             /// Some notice
             /// @notice Some other
@@ -71,18 +69,20 @@ mod tests {
                 None
             }
         }
-        ",
-        |sct: &EnumDefinition| Some(Violation::new(
+        ";
+
+    test_too_many_notice!(
+        no_tag_violation,
+        NO_TAG_VIOLATION_SOURCE,
+        |_: &EnumDefinition| Some(Violation::new(
             TooManyNotice::NAME,
             TooManyNotice::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Notice),
-            sct.loc
+            text_loc(NO_TAG_VIOLATION_SOURCE, "@notice Some other")
         )) // WARNING: solang parser and the natspec docs interpret no tags as a notice
     );
 
-    test_too_many_notice!(
-        multiline_no_tag_violation,
-        r"
+    const MULTILINE_NO_TAG_VIOLATION_SOURCE: &str = r"
         contract Test {
             /**
              * Some comment
@@ -93,12 +93,16 @@ mod tests {
                 None
             }
         }
-        ",
-        |sct: &EnumDefinition| Some(Violation::new(
+        ";
+
+    test_too_many_notice!(
+        multiline_no_tag_violation,
+        MULTILINE_NO_TAG_VIOLATION_SOURCE,
+        |_: &EnumDefinition| Some(Violation::new(
             TooManyNotice::NAME,
             TooManyNotice::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Notice),
-            sct.loc
+            text_loc(MULTILINE_NO_TAG_VIOLATION_SOURCE, "@notice Some othe")
         )) // WARNING: solang parser and the natspec docs interpret no tags as a notice
     );
 }
@@ -0,0 +1,136 @@
+//! This module defines the automatic fixes that rules can attach to their violations, applied
+//! with `natlint run --fix`.
+
+/// A replacement of a byte range of the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edit {
+    /// The start offset of the range.
+    pub start: usize,
+    /// The end offset of the range, exclusive.
+    pub end: usize,
+    /// The text replacing the range.
+    pub replacement: String,
+}
+
+impl Edit {
+    /// Create an edit inserting `text` at `offset`.
+    #[must_use]
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self {
+            start: offset,
+            end: offset,
+            replacement: text.into(),
+        }
+    }
+
+    /// Create an edit replacing the `start..end` range with `text`.
+    #[must_use]
+    pub fn replace(start: usize, end: usize, text: impl Into<String>) -> Self {
+        Self {
+            start,
+            end,
+            replacement: text.into(),
+        }
+    }
+}
+
+/// An automatic fix of a violation, made of edits applied together.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fix {
+    /// The edits of the fix, which must not overlap.
+    pub edits: Vec<Edit>,
+}
+
+impl Fix {
+    /// Create a fix from its edits.
+    #[must_use]
+    pub const fn new(edits: Vec<Edit>) -> Self {
+        Self { edits }
+    }
+
+    /// Returns `true` if an edit of the fix overlaps an edit of `other`.
+    /// Insertions at the same offset overlap, since their order would be ambiguous.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.edits.iter().any(|edit| {
+            other.edits.iter().any(|other| {
+                edit.start < other.end && other.start < edit.end || edit.start == other.start
+            })
+        })
+    }
+}
+
+/// Applies the fixes to the content.
+///
+/// A fix that overlaps a fix applied before it is skipped, so that a second run can apply it to
+/// the fixed content. Returns the fixed content, and whether each fix was applied.
+#[must_use]
+pub fn apply_fixes(content: &str, fixes: &[&Fix]) -> (String, Vec<bool>) {
+    let mut applied: Vec<&Fix> = vec![];
+    let results = fixes
+        .iter()
+        .map(|fix| {
+            let valid = fix.edits.iter().all(|edit| {
+                edit.start <= edit.end
+                    && edit.end <= content.len()
+                    && content.is_char_boundary(edit.start)
+                    && content.is_char_boundary(edit.end)
+            });
+            let apply = valid && !applied.iter().any(|other| fix.overlaps(other));
+            if apply {
+                applied.push(fix);
+            }
+            apply
+        })
+        .collect();
+
+    let mut edits = applied
+        .into_iter()
+        .flat_map(|fix| &fix.edits)
+        .collect::<Vec<_>>();
+    edits.sort_by_key(|edit| edit.start);
+
+    let mut output = String::with_capacity(content.len());
+    let mut offset = 0;
+    for edit in edits {
+        output.push_str(&content[offset..edit.start]);
+        output.push_str(&edit.replacement);
+        offset = edit.end;
+    }
+    output.push_str(&content[offset..]);
+
+    (output, results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_fixes, Edit, Fix};
+
+    #[test]
+    fn apply() {
+        let content = "// @notice Foo\n/* @dev Bar */\nfunction foo() {}";
+        let line = Fix::new(vec![Edit::insert(2, "/")]);
+        let block = Fix::new(vec![Edit::insert(17, "*")]);
+        let rename = Fix::new(vec![Edit::replace(39, 42, "bar")]);
+
+        assert_eq!(
+            apply_fixes(content, &[&rename, &line, &block]),
+            (
+                "/// @notice Foo\n/** @dev Bar */\nfunction bar() {}".to_owned(),
+                vec![true, true, true]
+            )
+        );
+    }
+
+    #[test]
+    fn overlapping() {
+        let content = "function foo() {}";
+        let first = Fix::new(vec![Edit::replace(9, 12, "bar")]);
+        let second = Fix::new(vec![Edit::replace(10, 11, "a")]);
+        let out_of_range = Fix::new(vec![Edit::insert(100, "a")]);
+
+        assert_eq!(
+            apply_fixes(content, &[&first, &second, &out_of_range]),
+            ("function bar() {}".to_owned(), vec![true, false, false])
+        );
+    }
+}
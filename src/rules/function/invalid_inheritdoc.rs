@@ -0,0 +1,208 @@
+use solang_parser::pt::FunctionDefinition;
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    rules::{context::RuleContext, violation_error::ViolationError},
+};
+
+use super::super::{Rule, Violation};
+
+/// This rule requires that the inheritdoc comments of functions name a base of their contract.
+///
+/// Without the rest of the file (i.e. through [`Rule::check`]) only the direct bases are known.
+pub struct InvalidInheritdoc;
+
+impl Rule for InvalidInheritdoc {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "InvalidInheritdoc";
+    const DESCRIPTION: &'static str =
+        "Function inheritdoc comments must reference a base of the contract.";
+
+    fn check(
+        parent: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        Self::check_with_context(&RuleContext::from_parent(parent), func, comments)
+    }
+
+    fn check_with_context(
+        ctx: &RuleContext,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let base = comments.find_inheritdoc_base()?;
+
+        // The inheritdoc base must be inherited by the parent contract
+        if ctx.is_inheritdoc_base(base) {
+            return None;
+        }
+
+        Some(Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            ViolationError::InvalidInheritdoc(base.to_owned()),
+            func.loc,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionDefinition, InvalidInheritdoc, Rule, RuleContext, Violation};
+    use crate::{
+        parser::{CommentsRef, ParseItem, Parser},
+        rules::violation_error::ViolationError,
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::{
+        parse,
+        pt::{Import, SourceUnitPart},
+    };
+
+    fn parse_source(src: &str) -> (Vec<ParseItem>, Vec<Import>) {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let imports = source
+            .0
+            .iter()
+            .filter_map(|part| match part {
+                SourceUnitPart::ImportDirective(import) => Some(import.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        (doc.items(), imports)
+    }
+
+    macro_rules! test_invalidinheritdoc {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let (items, imports) = parse_source($source);
+
+                let parent = items.last().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+                let ctx = RuleContext::new(Some(parent), &items, &imports);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    InvalidInheritdoc::check_with_context(&ctx, func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_invalidinheritdoc!(
+        no_inheritdoc_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_invalidinheritdoc!(
+        direct_base_no_violation,
+        r"
+        import {IFoo} from './IFoo.sol';
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_invalidinheritdoc!(
+        indirect_base_no_violation,
+        r"
+        interface IFoo {}
+        interface IBar is IFoo {}
+
+        contract Test is IBar {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_invalidinheritdoc!(
+        imported_not_a_base_violation,
+        r"
+        import {IFoo, IBar} from './IFoo.sol';
+
+        contract Test is IBar {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            InvalidInheritdoc::NAME,
+            InvalidInheritdoc::DESCRIPTION,
+            ViolationError::InvalidInheritdoc("IFoo".to_owned()),
+            func.loc
+        ))
+    );
+
+    test_invalidinheritdoc!(
+        not_a_base_violation,
+        r"
+        interface IFoo {}
+        interface IBar {}
+
+        contract Test is IBar {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            InvalidInheritdoc::NAME,
+            InvalidInheritdoc::DESCRIPTION,
+            ViolationError::InvalidInheritdoc("IFoo".to_owned()),
+            func.loc
+        ))
+    );
+
+    test_invalidinheritdoc!(
+        typo_violation,
+        r"
+        import {IERC20Permit} from './IERC20Permit.sol';
+
+        contract Test is IERC20Permit {
+            /// @inheritdoc IERC20Permitt
+            function test() public {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            InvalidInheritdoc::NAME,
+            InvalidInheritdoc::DESCRIPTION,
+            ViolationError::InvalidInheritdoc("IERC20Permitt".to_owned()),
+            func.loc
+        ))
+    );
+
+    test_invalidinheritdoc!(
+        no_bases_violation,
+        r"
+        contract Test {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            InvalidInheritdoc::NAME,
+            InvalidInheritdoc::DESCRIPTION,
+            ViolationError::InvalidInheritdoc("IFoo".to_owned()),
+            func.loc
+        ))
+    );
+}
@@ -0,0 +1,235 @@
+use solang_parser::pt::{FunctionDefinition, Loc, ParameterList};
+
+use crate::{
+    parser::{CommentTag, CommentsRef, ParseItem},
+    project::signature::function_signature,
+    rules::{context::RuleContext, violation_error::ViolationError},
+};
+
+use super::super::{Rule, Violation};
+
+/// This rule requires that functions with an inheritdoc comment name their parameters and return
+/// variables like the function they inherit the docs from.
+///
+/// The compiler copies the `@param` and `@return` comments of the base by name, so a renamed
+/// parameter silently loses its docs. The base function is matched by selector, which requires it
+/// to be declared in the same file unless the project is known.
+pub struct MismatchedInheritdocParams;
+
+impl Rule for MismatchedInheritdocParams {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "MismatchedInheritdocParams";
+    const DESCRIPTION: &'static str =
+        "Function parameters and return variables must be named like in the inheritdoc base.";
+
+    fn check(
+        parent: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        Self::check_with_context(&RuleContext::from_parent(parent), func, comments)
+    }
+
+    fn check_with_context(
+        ctx: &RuleContext,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let base = comments.find_inheritdoc_base()?;
+        let base_func = ctx
+            .inheritdoc_target(base, &function_signature(func)?)?
+            .as_function()?;
+
+        mismatched_name(&func.params, &base_func.params, &CommentTag::Param)
+            .or_else(|| mismatched_name(&func.returns, &base_func.returns, &CommentTag::Return))
+            .map(|(error, loc)| Violation::new(Self::NAME, Self::DESCRIPTION, error, loc))
+    }
+}
+
+/// Returns the error and the location of the first parameter named differently than in the base.
+/// Unnamed parameters, on either side, are not compared.
+fn mismatched_name(
+    params: &ParameterList,
+    base_params: &ParameterList,
+    tag: &CommentTag,
+) -> Option<(ViolationError, Loc)> {
+    params
+        .iter()
+        .zip(base_params.iter())
+        .find_map(|((loc, param), (_, base_param))| {
+            let name = &param.as_ref()?.name.as_ref()?.name;
+            let base_name = &base_param.as_ref()?.name.as_ref()?.name;
+
+            (name != base_name).then(|| {
+                (
+                    ViolationError::mismatched_name(tag.clone(), name, base_name),
+                    *loc,
+                )
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionDefinition, MismatchedInheritdocParams, Rule, RuleContext, Violation};
+    use crate::{
+        parser::{CommentTag, CommentsRef, ParseItem, Parser},
+        rules::violation_error::ViolationError,
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::{
+        parse,
+        pt::{Import, SourceUnitPart},
+    };
+
+    fn parse_source(src: &str) -> (Vec<ParseItem>, Vec<Import>) {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let imports = source
+            .0
+            .iter()
+            .filter_map(|part| match part {
+                SourceUnitPart::ImportDirective(import) => Some(import.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        (doc.items(), imports)
+    }
+
+    macro_rules! test_mismatchedinheritdocparams {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let (items, imports) = parse_source($source);
+
+                let parent = items.last().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+                let ctx = RuleContext::new(Some(parent), &items, &imports);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    MismatchedInheritdocParams::check_with_context(&ctx, func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_mismatchedinheritdocparams!(
+        no_inheritdoc_no_violation,
+        r"
+        interface IVault {
+            function deposit(uint256 assets) external;
+        }
+
+        contract Vault is IVault {
+            /// @notice Deposits
+            /// @param amount The amount
+            function deposit(uint256 amount) external {}
+        }
+        ",
+        |_| None
+    );
+
+    test_mismatchedinheritdocparams!(
+        same_names_no_violation,
+        r"
+        interface IVault {
+            function deposit(uint256 assets) external returns (uint256 shares);
+        }
+
+        contract Vault is IVault {
+            /// @inheritdoc IVault
+            function deposit(uint256 assets) external returns (uint256 shares) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_mismatchedinheritdocparams!(
+        unnamed_no_violation,
+        r"
+        interface IVault {
+            function deposit(uint256 assets) external returns (uint256 shares);
+        }
+
+        contract Vault is IVault {
+            /// @inheritdoc IVault
+            function deposit(uint256) external returns (uint256) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_mismatchedinheritdocparams!(
+        unknown_base_no_violation,
+        r"
+        import {IVault} from './IVault.sol';
+
+        contract Vault is IVault {
+            /// @inheritdoc IVault
+            function deposit(uint256 amount) external {}
+        }
+        ",
+        |_| None
+    );
+
+    test_mismatchedinheritdocparams!(
+        different_selector_no_violation,
+        r"
+        interface IVault {
+            function deposit(uint256 assets, address receiver) external;
+        }
+
+        contract Vault is IVault {
+            /// @inheritdoc IVault
+            function deposit(uint256 amount) external {}
+        }
+        ",
+        |_| None
+    );
+
+    test_mismatchedinheritdocparams!(
+        renamed_param_violation,
+        r"
+        interface IVault {
+            function deposit(uint256 assets, address receiver) external;
+        }
+
+        contract Vault is IVault {
+            /// @inheritdoc IVault
+            function deposit(uint256 amount, address receiver) external {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MismatchedInheritdocParams::NAME,
+            MismatchedInheritdocParams::DESCRIPTION,
+            ViolationError::mismatched_name(CommentTag::Param, "amount", "assets"),
+            func.params[0].0
+        ))
+    );
+
+    test_mismatchedinheritdocparams!(
+        renamed_return_violation,
+        r"
+        interface IVault {
+            function deposit(uint256 assets) external returns (uint256 shares);
+        }
+
+        contract Vault is IVault {
+            /// @inheritdoc IVault
+            function deposit(uint256 assets) external returns (uint256 minted) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MismatchedInheritdocParams::NAME,
+            MismatchedInheritdocParams::DESCRIPTION,
+            ViolationError::mismatched_name(CommentTag::Return, "minted", "shares"),
+            func.returns[0].0
+        ))
+    );
+}
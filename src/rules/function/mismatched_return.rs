@@ -0,0 +1,377 @@
+use std::any::{Any, TypeId};
+
+use solang_parser::pt::{FunctionDefinition, FunctionTy};
+
+use crate::{
+    parser::{CommentTag, CommentsRef},
+    rules::violation_error::ViolationError,
+};
+
+use super::{
+    super::{context::RuleContext, DynRule, Violation},
+    UnnamedParams,
+};
+
+/// This rule requires that the `@return` comments of functions document their return variables in
+/// order, each comment of a named return variable starting with its name.
+///
+/// The compiler matches the `@return` comments with the return variables by position, so
+/// `returns (uint256 shares, uint256 assets)` documented as `@return assets` then `@return shares`
+/// describes each value as the other one. Each mismatched comment is reported. Missing and extra
+/// comments, and names that no comment documents, are left to
+/// [`MissingReturn`](super::MissingReturn). Return variables named with `@custom:name` are named
+/// according to the [`UnnamedParams`] policy.
+pub struct MismatchedReturn {
+    unnamed: UnnamedParams,
+}
+
+impl MismatchedReturn {
+    /// The name of the rule.
+    pub const NAME: &'static str = "MismatchedReturn";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Return comments must document the return variables in order, starting with their name.";
+
+    /// Create the rule, naming the return variables with the given policy.
+    #[must_use]
+    pub const fn new(unnamed: UnnamedParams) -> Self {
+        Self { unnamed }
+    }
+
+    /// Check the order of the return comments of the function, reporting each mismatched one.
+    #[must_use]
+    pub fn check(&self, func: &FunctionDefinition, comments: &CommentsRef) -> Vec<Violation> {
+        // Function type must be a user function
+        match func.ty {
+            FunctionTy::Function => (),
+            FunctionTy::Receive
+            | FunctionTy::Fallback
+            | FunctionTy::Modifier
+            | FunctionTy::Constructor => return vec![],
+        }
+
+        // If the function has an inheritdoc comment, the returns are documented in the base
+        if comments.find_inheritdoc_base().is_some() {
+            return vec![];
+        }
+
+        let returns = self.unnamed.returns(func);
+        let names = returns
+            .iter()
+            .map(|(_, param)| Some(param.as_ref()?.name.as_ref()?.name.as_str()))
+            .collect::<Vec<_>>();
+        let return_comments = comments.include_tag(CommentTag::Return);
+        let words = return_comments
+            .iter()
+            .map(|comment| comment.value.split_whitespace().next())
+            .collect::<Vec<_>>();
+
+        returns
+            .iter()
+            .zip(return_comments.iter().zip(&words))
+            .enumerate()
+            .filter_map(|(i, ((loc, _), (comment, word)))| {
+                let position = i + 1;
+
+                // The comment documents another return variable
+                let other = word
+                    .and_then(|word| (0..names.len()).find(|j| *j != i && names[*j] == Some(word)));
+                let error = match (other, names[i]) {
+                    (Some(j), _) => ViolationError::MisplacedReturn {
+                        position,
+                        name: word.unwrap_or_default().to_owned(),
+                        expected: j + 1,
+                    },
+                    // A name that no comment documents is reported by `MissingReturn`
+                    (None, Some(name)) if *word != Some(name) && words.contains(&Some(name)) => {
+                        ViolationError::ReturnNameMismatch {
+                            position,
+                            name: name.to_owned(),
+                        }
+                    }
+                    _ => return None,
+                };
+
+                Some(Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    error,
+                    comment.loc_or(*loc),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Default for MismatchedReturn {
+    fn default() -> Self {
+        Self::new(UnnamedParams::default())
+    }
+}
+
+impl DynRule for MismatchedReturn {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<FunctionDefinition>()
+    }
+
+    fn check_dyn(
+        &self,
+        ctx: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        self.check_all(ctx, item, comments).into_iter().next()
+    }
+
+    fn check_all(&self, _: &RuleContext, item: &dyn Any, comments: &CommentsRef) -> Vec<Violation> {
+        let item = item
+            .downcast_ref::<FunctionDefinition>()
+            .expect("Item type mismatch");
+        self.check(item, comments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommentsRef, MismatchedReturn, UnnamedParams, Violation};
+    use crate::{
+        parser::Parser,
+        rules::{macros::text_loc, violation_error::ViolationError},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    fn violation(error: ViolationError, src: &str, comment: &str) -> Violation {
+        Violation::new(
+            MismatchedReturn::NAME,
+            MismatchedReturn::DESCRIPTION,
+            error,
+            text_loc(src, comment),
+        )
+    }
+
+    macro_rules! test_mismatchedreturn {
+        ($name:ident, $source:expr, $expected:expr) => {
+            test_mismatchedreturn!($name, Positional, $source, $expected);
+        };
+        ($name:ident, $unnamed:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected: Vec<Violation> = $expected($source);
+
+                assert_eq!(
+                    MismatchedReturn::new(UnnamedParams::$unnamed).check(func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_mismatchedreturn!(
+        ordered_no_violation,
+        r"
+        contract Test {
+            /// @return shares The shares
+            /// @return The fee
+            /// @return assets The assets
+            function test() public returns (uint256 shares, uint256, uint256 assets) {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_mismatchedreturn!(
+        missing_comment_no_violation,
+        r"
+        contract Test {
+            /// @return shares The shares
+            function test() public returns (uint256 shares, uint256 assets) {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_mismatchedreturn!(
+        inheritdoc_no_violation,
+        r"
+        contract Test {
+            /// @inheritdoc Base
+            function test() public returns (uint256 shares, uint256 assets) {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_mismatchedreturn!(
+        undocumented_name_no_violation,
+        r"
+        contract Test {
+            /// @return The shares
+            function test() public returns (uint256 shares) {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_mismatchedreturn!(
+        positional_custom_name_no_violation,
+        r"
+        contract Test {
+            /// @return Whether it worked
+            function test() public returns (/** @custom:name success */ bool) {}
+        }
+        ",
+        |_| vec![]
+    );
+
+    test_mismatchedreturn!(
+        swapped_violation,
+        r"
+        contract Test {
+            /// @return assets The assets
+            /// @return shares The shares
+            function test() public returns (uint256 shares, uint256 assets) {}
+        }
+        ",
+        |src| vec![
+            violation(
+                ViolationError::MisplacedReturn {
+                    position: 1,
+                    name: "assets".to_owned(),
+                    expected: 2
+                },
+                src,
+                "@return assets The assets"
+            ),
+            violation(
+                ViolationError::MisplacedReturn {
+                    position: 2,
+                    name: "shares".to_owned(),
+                    expected: 1
+                },
+                src,
+                "@return shares The shares"
+            ),
+        ]
+    );
+
+    test_mismatchedreturn!(
+        unnamed_slot_violation,
+        r"
+        contract Test {
+            /**
+             * @return The fee
+             * @return The fee again
+             * @return shares The shares
+             */
+            function test() public returns (uint256, uint256 shares, uint256) {}
+        }
+        ",
+        |src| vec![
+            violation(
+                ViolationError::ReturnNameMismatch {
+                    position: 2,
+                    name: "shares".to_owned()
+                },
+                src,
+                "@return The fee again"
+            ),
+            violation(
+                ViolationError::MisplacedReturn {
+                    position: 3,
+                    name: "shares".to_owned(),
+                    expected: 2
+                },
+                src,
+                "@return shares The shares"
+            ),
+        ]
+    );
+
+    test_mismatchedreturn!(
+        documented_in_unnamed_slot_violation,
+        r"
+        contract Test {
+            /// @return shares The shares
+            /// @return The fee
+            function test() public returns (uint256, uint256 shares) {}
+        }
+        ",
+        |src| vec![
+            violation(
+                ViolationError::MisplacedReturn {
+                    position: 1,
+                    name: "shares".to_owned(),
+                    expected: 2
+                },
+                src,
+                "@return shares The shares"
+            ),
+            violation(
+                ViolationError::ReturnNameMismatch {
+                    position: 2,
+                    name: "shares".to_owned()
+                },
+                src,
+                "@return The fee"
+            ),
+        ]
+    );
+
+    test_mismatchedreturn!(
+        custom_name_violation,
+        CustomName,
+        r"
+        contract Test {
+            /// @return amount The amount
+            /// @return success Whether it worked
+            function test()
+                public
+                returns (/** @custom:name success */ bool, /** @custom:name amount */ uint256)
+            {}
+        }
+        ",
+        |src| vec![
+            violation(
+                ViolationError::MisplacedReturn {
+                    position: 1,
+                    name: "amount".to_owned(),
+                    expected: 2
+                },
+                src,
+                "@return amount The amount"
+            ),
+            violation(
+                ViolationError::MisplacedReturn {
+                    position: 2,
+                    name: "success".to_owned(),
+                    expected: 1
+                },
+                src,
+                "@return success Whether it worked"
+            ),
+        ]
+    );
+}
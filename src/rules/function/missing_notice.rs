@@ -1,4 +1,4 @@
-use solang_parser::pt::FunctionDefinition;
+use solang_parser::pt::{FunctionDefinition, FunctionTy};
 
 use crate::{
     parser::{CommentTag, CommentsRef, ParseItem},
@@ -20,6 +20,15 @@ impl Rule for MissingNotice {
         func: &FunctionDefinition,
         comments: &CommentsRef,
     ) -> Option<Violation> {
+        // Function type must be a user function
+        match func.ty {
+            FunctionTy::Function => (),
+            FunctionTy::Receive
+            | FunctionTy::Fallback
+            | FunctionTy::Modifier
+            | FunctionTy::Constructor => return None,
+        }
+
         // If the function has an inheritdoc comment, it is exempt from this rule
         if comments.find_inheritdoc_base().is_some() {
             return None;
@@ -153,6 +162,36 @@ mod tests {
         );
     }
 
+    test_missingnotice!(
+        modifier_no_violation,
+        r"
+        contract Test {
+            modifier onlyOwner() { _; }
+        }
+        ",
+        |_| None
+    );
+
+    test_missingnotice!(
+        constructor_no_violation,
+        r"
+        contract Test {
+            constructor() {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingnotice!(
+        receive_no_violation,
+        r"
+        contract Test {
+            receive() external payable {}
+        }
+        ",
+        |_| None
+    );
+
     mod private_test {
         use super::*;
 
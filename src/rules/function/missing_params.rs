@@ -1,31 +1,45 @@
+use std::any::{Any, TypeId};
+
 use solang_parser::pt::{FunctionDefinition, FunctionTy};
 
-use crate::{
-    parser::{CommentTag, CommentsRef, ParseItem},
-    rules::violation_error::ViolationError,
-};
+use crate::parser::{CommentTag, CommentsRef};
 
-use super::super::{Rule, Violation};
+use super::{
+    super::{context::RuleContext, DynRule, Violation},
+    UnnamedParams,
+};
 
 /// This rule requires that all functions have their parameters documented or have an inheritdoc
 /// comment.
-pub struct MissingParams;
+///
+/// Unnamed parameters are checked according to the [`UnnamedParams`] policy.
+pub struct MissingParams {
+    unnamed: UnnamedParams,
+}
 
-impl Rule for MissingParams {
-    type Target = FunctionDefinition;
-    const NAME: &'static str = "MissingParams";
-    const DESCRIPTION: &'static str =
+impl MissingParams {
+    /// The name of the rule.
+    pub const NAME: &'static str = "MissingParams";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
         "Functions must have their parameters documented or have an inheritdoc comment.";
 
-    fn check(
-        _: Option<&ParseItem>,
-        func: &FunctionDefinition,
-        comments: &CommentsRef,
-    ) -> Option<Violation> {
-        // Function must not be a modifier or constructor
+    /// Create the rule, checking the unnamed parameters with the given policy.
+    #[must_use]
+    pub const fn new(unnamed: UnnamedParams) -> Self {
+        Self { unnamed }
+    }
+
+    /// Check that the parameters of the function are documented.
+    #[must_use]
+    pub fn check(&self, func: &FunctionDefinition, comments: &CommentsRef) -> Option<Violation> {
+        // Function type must be a user function
         match func.ty {
-            FunctionTy::Function | FunctionTy::Constructor | FunctionTy::Modifier => (),
-            FunctionTy::Receive | FunctionTy::Fallback => return None,
+            FunctionTy::Function => (),
+            FunctionTy::Receive
+            | FunctionTy::Fallback
+            | FunctionTy::Modifier
+            | FunctionTy::Constructor => return None,
         }
 
         // If the function has an inheritdoc comment, it is exempt from this rule
@@ -33,62 +47,53 @@ impl Rule for MissingParams {
             return None;
         }
 
-        // Function must have a parameter comment for each parameter
-        let param_comments = comments.include_tag(CommentTag::Param);
-        match func.params.len().cmp(&param_comments.len()) {
-            std::cmp::Ordering::Less => {
-                return Some(Violation::new(
-                    Self::NAME,
-                    Self::DESCRIPTION,
-                    ViolationError::TooManyComments(CommentTag::Param),
-                    func.loc,
-                ));
-            }
-            std::cmp::Ordering::Greater => {
-                return Some(Violation::new(
-                    Self::NAME,
-                    Self::DESCRIPTION,
-                    ViolationError::MissingComment(CommentTag::Param),
-                    func.loc,
-                ));
-            }
-            std::cmp::Ordering::Equal => (),
-        }
-        for (loc, param) in &func.params {
-            let Some(param_name) = param
-                .as_ref()
-                .and_then(|p| p.name.as_ref().map(|id| id.name.clone()))
-            else {
-                // Skip unnamed parameters
-                continue;
-            };
-
-            if !param_comments.iter().any(|comment| {
-                comment
-                    .split_first_word()
-                    .map(|(name, _)| name.to_owned())
-                    .unwrap_or_default()
-                    == param_name
-            }) {
-                return Some(Violation::new(
-                    Self::NAME,
-                    Self::DESCRIPTION,
-                    ViolationError::missing_comment_for(CommentTag::Param, &param_name),
-                    *loc,
-                ));
-            }
-        }
+        // Function must have a param comment for each parameter
+        super::check_unnamed_params(func, &func.params, &CommentTag::Param, self.unnamed)
+            .or_else(|| {
+                super::check_parameter_docs(&func.params, &CommentTag::Param, comments, func.loc)
+            })
+            .map(|(error, loc)| Violation::new(Self::NAME, Self::DESCRIPTION, error, loc))
+    }
+}
 
-        None
+impl Default for MissingParams {
+    fn default() -> Self {
+        Self::new(UnnamedParams::default())
+    }
+}
+
+impl DynRule for MissingParams {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<FunctionDefinition>()
+    }
+
+    fn check_dyn(
+        &self,
+        _: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<FunctionDefinition>()
+            .expect("Item type mismatch");
+        self.check(item, comments)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        CommentTag, CommentsRef, FunctionDefinition, MissingParams, Rule, Violation, ViolationError,
+        CommentTag, CommentsRef, FunctionDefinition, MissingParams, UnnamedParams, Violation,
     };
-    use crate::parser::Parser;
+    use crate::{parser::Parser, rules::violation_error::ViolationError};
     use forge_fmt::Visitable;
     use solang_parser::parse;
 
@@ -101,6 +106,9 @@ mod tests {
 
     macro_rules! test_missingparams {
         ($name:ident, $source:expr, $expected:expr) => {
+            test_missingparams!($name, Positional, $source, $expected);
+        };
+        ($name:ident, $unnamed:ident, $source:expr, $expected:expr) => {
             #[test]
             fn $name() {
                 let src = parse_source($source);
@@ -113,7 +121,7 @@ mod tests {
                 let expected = $expected(func);
 
                 assert_eq!(
-                    MissingParams::check(Some(parent), func, &comments),
+                    MissingParams::new(UnnamedParams::$unnamed).check(func, &comments),
                     expected
                 );
             }
@@ -235,6 +243,26 @@ mod tests {
         |_| None
     );
 
+    test_missingparams!(
+        modifier_no_violation,
+        r"
+        contract Test {
+            modifier onlyRole(bytes32 role) { _; }
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        constructor_no_violation,
+        r"
+        contract Test {
+            constructor(uint256 a) {}
+        }
+        ",
+        |_| None
+    );
+
     test_missingparams!(
         public_violation,
         r"
@@ -338,4 +366,101 @@ mod tests {
             func.params[1].0
         ))
     );
+
+    test_missingparams!(
+        unnamed_positional_no_violation,
+        r"
+        contract Test {
+            /// @param a A number
+            /// @param The flag
+            function test(uint256 a, bool) public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        unnamed_documented_twice_violation,
+        r"
+        contract Test {
+            /// @param a A number
+            /// @param a The same number
+            function test(uint256 a, bool) public {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::MissingUnnamedComment {
+                tag: CommentTag::Param,
+                position: 2
+            },
+            func.params[1].0
+        ))
+    );
+
+    test_missingparams!(
+        custom_name_no_violation,
+        CustomName,
+        r"
+        contract Test {
+            /// @param amount The amount
+            function test(/** @custom:name amount */ uint256) public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        custom_name_violation,
+        CustomName,
+        r"
+        contract Test {
+            /// @param a A number
+            /// @param The flag
+            function test(uint256 a, bool) internal {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::UnnamedWithoutCustomName {
+                tag: CommentTag::Param,
+                position: 2
+            },
+            func.params[1].0
+        ))
+    );
+
+    test_missingparams!(
+        forbid_internal_no_violation,
+        Forbid,
+        r"
+        contract Test {
+            /// @param A number
+            function test(uint256) internal {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        forbid_public_violation,
+        Forbid,
+        r"
+        contract Test {
+            /// @param amount The amount
+            function test(/** @custom:name amount */ uint256) external {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::UnnamedInPublicApi {
+                tag: CommentTag::Param,
+                position: 1
+            },
+            func.params[0].0
+        ))
+    );
 }
@@ -1,26 +1,37 @@
+use std::any::{Any, TypeId};
+
 use solang_parser::pt::{FunctionDefinition, FunctionTy};
 
-use crate::{
-    parser::{CommentTag, CommentsRef, ParseItem},
-    rules::violation_error::ViolationError,
-};
+use crate::parser::{CommentTag, CommentsRef};
 
-use super::super::{Rule, Violation};
+use super::{
+    super::{context::RuleContext, DynRule, Violation},
+    UnnamedParams,
+};
 
 /// This rule requires that all functions have their return variables documented or have an inheritdoc comment.
-pub struct MissingReturn;
+///
+/// Unnamed return variables are checked according to the [`UnnamedParams`] policy.
+pub struct MissingReturn {
+    unnamed: UnnamedParams,
+}
 
-impl Rule for MissingReturn {
-    type Target = FunctionDefinition;
-    const NAME: &'static str = "MissingReturn";
-    const DESCRIPTION: &'static str =
+impl MissingReturn {
+    /// The name of the rule.
+    pub const NAME: &'static str = "MissingReturn";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
         "Functions must have their return variables documented or have an inheritdoc comment.";
 
-    fn check(
-        _: Option<&ParseItem>,
-        func: &FunctionDefinition,
-        comments: &CommentsRef,
-    ) -> Option<Violation> {
+    /// Create the rule, checking the unnamed return variables with the given policy.
+    #[must_use]
+    pub const fn new(unnamed: UnnamedParams) -> Self {
+        Self { unnamed }
+    }
+
+    /// Check that the return variables of the function are documented.
+    #[must_use]
+    pub fn check(&self, func: &FunctionDefinition, comments: &CommentsRef) -> Option<Violation> {
         // Function type must be a user function
         match func.ty {
             FunctionTy::Function => (),
@@ -36,61 +47,53 @@ impl Rule for MissingReturn {
         }
 
         // Function must have a return comment for each return variable
-        let return_comments = comments.include_tag(CommentTag::Return);
-        match func.returns.len().cmp(&return_comments.len()) {
-            std::cmp::Ordering::Less => {
-                return Some(Violation::new(
-                    Self::NAME,
-                    Self::DESCRIPTION,
-                    ViolationError::TooManyComments(CommentTag::Return),
-                    func.loc,
-                ));
-            }
-            std::cmp::Ordering::Greater => {
-                return Some(Violation::new(
-                    Self::NAME,
-                    Self::DESCRIPTION,
-                    ViolationError::MissingComment(CommentTag::Return),
-                    func.loc,
-                ));
-            }
-            std::cmp::Ordering::Equal => (),
-        }
-        for (loc, return_var) in &func.returns {
-            let Some(var_name) = return_var
-                .as_ref()
-                .and_then(|p| p.name.as_ref().map(|id| id.name.clone()))
-            else {
-                // Skip unnamed parameters
-                continue;
-            };
-
-            if !return_comments.iter().any(|comment| {
-                comment
-                    .split_first_word()
-                    .map(|(name, _)| name.to_owned())
-                    .unwrap_or_default()
-                    == var_name
-            }) {
-                return Some(Violation::new(
-                    Self::NAME,
-                    Self::DESCRIPTION,
-                    ViolationError::missing_comment_for(CommentTag::Return, &var_name),
-                    *loc,
-                ));
-            }
-        }
+        let returns = self.unnamed.returns(func);
+        super::check_unnamed_params(func, &returns, &CommentTag::Return, self.unnamed)
+            .or_else(|| {
+                super::check_parameter_docs(&returns, &CommentTag::Return, comments, func.loc)
+            })
+            .map(|(error, loc)| Violation::new(Self::NAME, Self::DESCRIPTION, error, loc))
+    }
+}
+
+impl Default for MissingReturn {
+    fn default() -> Self {
+        Self::new(UnnamedParams::default())
+    }
+}
+
+impl DynRule for MissingReturn {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
 
-        None
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<FunctionDefinition>()
+    }
+
+    fn check_dyn(
+        &self,
+        _: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<FunctionDefinition>()
+            .expect("Item type mismatch");
+        self.check(item, comments)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        CommentTag, CommentsRef, FunctionDefinition, MissingReturn, Rule, Violation, ViolationError,
+        CommentTag, CommentsRef, FunctionDefinition, MissingReturn, UnnamedParams, Violation,
     };
-    use crate::parser::Parser;
+    use crate::{parser::Parser, rules::violation_error::ViolationError};
     use forge_fmt::Visitable;
     use solang_parser::parse;
 
@@ -103,6 +106,9 @@ mod tests {
 
     macro_rules! test_missingreturn {
         ($name:ident, $source:expr, $expected:expr) => {
+            test_missingreturn!($name, Positional, $source, $expected);
+        };
+        ($name:ident, $unnamed:ident, $source:expr, $expected:expr) => {
             #[test]
             fn $name() {
                 let src = parse_source($source);
@@ -115,7 +121,7 @@ mod tests {
                 let expected = $expected(func);
 
                 assert_eq!(
-                    MissingReturn::check(Some(parent), func, &comments),
+                    MissingReturn::new(UnnamedParams::$unnamed).check(func, &comments),
                     expected
                 );
             }
@@ -354,4 +360,112 @@ mod tests {
             func.returns[1].0
         ))
     );
+
+    test_missingreturn!(
+        unnamed_first_no_violation,
+        r"
+        contract Test {
+            /// @return b Some string
+            /// @return The count
+            function test(uint256) public returns (uint256, string memory b) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        unnamed_documented_twice_violation,
+        r"
+        contract Test {
+            /// @return a A number
+            /// @return a The same number
+            function test(uint256) public returns (uint256 a, uint256) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingReturn::NAME,
+            MissingReturn::DESCRIPTION,
+            ViolationError::MissingUnnamedComment {
+                tag: CommentTag::Return,
+                position: 2
+            },
+            func.returns[1].0
+        ))
+    );
+
+    test_missingreturn!(
+        positional_custom_name_no_violation,
+        r"
+        contract Test {
+            /// @return Whether it worked
+            function test(uint256) public returns (/** @custom:name success */ bool) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        custom_name_no_violation,
+        CustomName,
+        r"
+        contract Test {
+            /// @return count A number
+            function test(uint256) public returns (/** @custom:name count */ uint256) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        custom_name_violation,
+        CustomName,
+        r"
+        contract Test {
+            /// @return A number
+            function test(uint256) public returns (uint256) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingReturn::NAME,
+            MissingReturn::DESCRIPTION,
+            ViolationError::UnnamedWithoutCustomName {
+                tag: CommentTag::Return,
+                position: 1
+            },
+            func.returns[0].0
+        ))
+    );
+
+    test_missingreturn!(
+        forbid_private_no_violation,
+        Forbid,
+        r"
+        contract Test {
+            /// @return A number
+            function test(uint256) private returns (uint256) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        forbid_public_violation,
+        Forbid,
+        r"
+        contract Test {
+            /// @return a A number
+            /// @return Some string
+            function test(uint256) public returns (uint256 a, string memory) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingReturn::NAME,
+            MissingReturn::DESCRIPTION,
+            ViolationError::UnnamedInPublicApi {
+                tag: CommentTag::Return,
+                position: 2
+            },
+            func.returns[1].0
+        ))
+    );
 }
@@ -1,5 +1,19 @@
 //! This module defines the rules for function items in the natlint linter.
 
+use std::{borrow::Cow, cmp::Ordering, collections::BTreeMap};
+
+use solang_parser::pt::{FunctionDefinition, FunctionTy, Identifier, Loc, ParameterList};
+
+use crate::parser::{CommentTag, CommentsRef};
+
+use super::{
+    scope::{RuleFilter, Visibility},
+    violation_error::ViolationError,
+};
+
+mod invalid_inheritdoc;
+mod mismatched_inheritdoc_params;
+mod mismatched_return;
 mod missing_inheritdoc;
 mod missing_notice;
 mod missing_params;
@@ -9,8 +23,12 @@ mod no_title;
 mod only_inheritdoc;
 mod too_many_inheritdoc;
 mod too_many_notice;
+mod unmatched_inheritdoc;
 
 // The rules are exported here:
+pub use invalid_inheritdoc::InvalidInheritdoc;
+pub use mismatched_inheritdoc_params::MismatchedInheritdocParams;
+pub use mismatched_return::MismatchedReturn;
 pub use missing_inheritdoc::MissingInheritdoc;
 pub use missing_notice::MissingNotice;
 pub use missing_params::MissingParams;
@@ -20,11 +38,64 @@ pub use no_title::NoTitle;
 pub use only_inheritdoc::OnlyInheritdoc;
 pub use too_many_inheritdoc::TooManyInheritdoc;
 pub use too_many_notice::TooManyNotice;
+pub use unmatched_inheritdoc::UnmatchedInheritdoc;
+
+/// How the unnamed parameters and return variables of functions must be documented.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnnamedParams {
+    /// Each unnamed slot is documented by the `@param` or `@return` comment at its position,
+    /// among the comments that do not document a named slot. Return variables named with
+    /// `@custom:name` still count as unnamed.
+    #[default]
+    Positional,
+    /// Unnamed slots must be named with an inline `@custom:name` comment, e.g.
+    /// `/** @custom:name amount */ uint256`, and are then documented by that name.
+    CustomName,
+    /// Public and external functions must name all their slots, other functions are checked
+    /// positionally.
+    Forbid,
+}
+
+impl UnnamedParams {
+    /// Returns the return variables of the function as checked under the policy.
+    ///
+    /// The parser names the unnamed return variables after their `@custom:name` comment, but these
+    /// names only document them under [`CustomName`](Self::CustomName), so they are dropped under
+    /// the other policies, where the return variables are documented by position.
+    pub(crate) fn returns(self, func: &FunctionDefinition) -> Cow<'_, ParameterList> {
+        let custom_named = |name: &Identifier| name.loc == Loc::Implicit;
+        if self == Self::CustomName
+            || !func.returns.iter().any(|(_, param)| {
+                param
+                    .as_ref()
+                    .and_then(|param| param.name.as_ref())
+                    .is_some_and(custom_named)
+            })
+        {
+            return Cow::Borrowed(&func.returns);
+        }
+
+        let mut returns = func.returns.clone();
+        for param in returns.iter_mut().filter_map(|(_, param)| param.as_mut()) {
+            if param.name.as_ref().is_some_and(custom_named) {
+                param.name = None;
+            }
+        }
+        Cow::Owned(returns)
+    }
+}
 
 /// Configuration for function rules in the linter.
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[allow(missing_docs, clippy::struct_excessive_bools)]
 pub struct FunctionRulesConfig {
+    #[serde(default = "FunctionRulesConfig::default_invalid_inheritdoc")]
+    pub invalid_inheritdoc: bool,
+    #[serde(default = "FunctionRulesConfig::default_mismatched_inheritdoc_params")]
+    pub mismatched_inheritdoc_params: bool,
+    #[serde(default = "FunctionRulesConfig::default_mismatched_return")]
+    pub mismatched_return: bool,
     #[serde(default = "FunctionRulesConfig::default_missing_inheritdoc")]
     pub missing_inheritdoc: bool,
     #[serde(default = "FunctionRulesConfig::default_missing_notice")]
@@ -43,11 +114,22 @@ pub struct FunctionRulesConfig {
     pub too_many_inheritdoc: bool,
     #[serde(default = "FunctionRulesConfig::default_too_many_notice")]
     pub too_many_notice: bool,
+    #[serde(default = "FunctionRulesConfig::default_unmatched_inheritdoc")]
+    pub unmatched_inheritdoc: bool,
+    /// How `missing_params`, `missing_return` and `mismatched_return` check the unnamed
+    /// parameters and return variables.
+    #[serde(default)]
+    pub unnamed_params: UnnamedParams,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub filters: BTreeMap<String, RuleFilter>,
 }
 
 impl Default for FunctionRulesConfig {
     fn default() -> Self {
         Self {
+            invalid_inheritdoc: true,
+            mismatched_inheritdoc_params: true,
+            mismatched_return: true,
             missing_inheritdoc: true,
             missing_notice: true,
             missing_params: true,
@@ -57,16 +139,29 @@ impl Default for FunctionRulesConfig {
             only_inheritdoc: false,
             too_many_inheritdoc: true,
             too_many_notice: true,
+            unmatched_inheritdoc: true,
+            unnamed_params: UnnamedParams::default(),
+            filters: BTreeMap::new(),
         }
     }
 }
 
 impl FunctionRulesConfig {
     /// Returns an iterator over the rules in the configuration.
+    /// Rules with an entry in `filters` only apply to the matching items.
     #[must_use]
     pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
         let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
 
+        if self.invalid_inheritdoc {
+            rules.push(Box::new(InvalidInheritdoc));
+        }
+        if self.mismatched_inheritdoc_params {
+            rules.push(Box::new(MismatchedInheritdocParams));
+        }
+        if self.mismatched_return {
+            rules.push(Box::new(MismatchedReturn::new(self.unnamed_params)));
+        }
         if self.missing_inheritdoc {
             rules.push(Box::new(MissingInheritdoc));
         }
@@ -74,10 +169,10 @@ impl FunctionRulesConfig {
             rules.push(Box::new(MissingNotice));
         }
         if self.missing_params {
-            rules.push(Box::new(MissingParams));
+            rules.push(Box::new(MissingParams::new(self.unnamed_params)));
         }
         if self.missing_return {
-            rules.push(Box::new(MissingReturn));
+            rules.push(Box::new(MissingReturn::new(self.unnamed_params)));
         }
         if self.no_author {
             rules.push(Box::new(NoAuthor));
@@ -94,10 +189,22 @@ impl FunctionRulesConfig {
         if self.too_many_notice {
             rules.push(Box::new(TooManyNotice));
         }
+        if self.unmatched_inheritdoc {
+            rules.push(Box::new(UnmatchedInheritdoc));
+        }
 
-        rules
+        super::scope::apply_filters(rules, &self.filters)
     }
 
+    fn default_invalid_inheritdoc() -> bool {
+        Self::default().invalid_inheritdoc
+    }
+    fn default_mismatched_inheritdoc_params() -> bool {
+        Self::default().mismatched_inheritdoc_params
+    }
+    fn default_mismatched_return() -> bool {
+        Self::default().mismatched_return
+    }
     fn default_missing_inheritdoc() -> bool {
         Self::default().missing_inheritdoc
     }
@@ -125,4 +232,113 @@ impl FunctionRulesConfig {
     fn default_too_many_notice() -> bool {
         Self::default().too_many_notice
     }
+    fn default_unmatched_inheritdoc() -> bool {
+        Self::default().unmatched_inheritdoc
+    }
+}
+
+/// Returns `true` if the function definition is not a modifier, modifiers are covered by the
+/// modifier rules.
+pub(crate) const fn is_not_modifier(func: &FunctionDefinition) -> bool {
+    !matches!(func.ty, FunctionTy::Modifier)
+}
+
+/// Checks that every parameter of a parameter list is documented with a `tag` comment.
+/// Returns the error and the location of the first undocumented parameter, or `loc` if the number
+/// of comments does not match.
+///
+/// Named parameters are documented by the comment starting with their name, and unnamed ones by
+/// the remaining comments in order, so a comment documenting a named parameter twice does not
+/// count for an unnamed one.
+pub(crate) fn check_parameter_docs(
+    params: &ParameterList,
+    tag: &CommentTag,
+    comments: &CommentsRef,
+    loc: Loc,
+) -> Option<(ViolationError, Loc)> {
+    let param_comments = comments.include_tag(tag.clone());
+    match params.len().cmp(&param_comments.len()) {
+        Ordering::Less => return Some((ViolationError::TooManyComments(tag.clone()), loc)),
+        Ordering::Greater => return Some((ViolationError::MissingComment(tag.clone()), loc)),
+        Ordering::Equal => (),
+    }
+
+    let names = params
+        .iter()
+        .map(|(_, param)| Some(&param.as_ref()?.name.as_ref()?.name))
+        .collect::<Vec<_>>();
+    let first_words = param_comments
+        .iter()
+        .map(|comment| comment.split_first_word().map(|(word, _)| word))
+        .collect::<Vec<_>>();
+
+    let mut documented = vec![false; param_comments.len()];
+    for ((param_loc, _), name) in params.iter().zip(&names) {
+        let Some(name) = name else {
+            continue;
+        };
+        let Some(i) = (0..first_words.len())
+            .find(|i| !documented[*i] && first_words[*i] == Some(name.as_str()))
+        else {
+            return Some((
+                ViolationError::missing_comment_for(tag.clone(), *name),
+                *param_loc,
+            ));
+        };
+        documented[i] = true;
+    }
+
+    let mut remaining = (0..first_words.len()).filter(|i| {
+        !documented[*i]
+            && !first_words[*i].is_some_and(|word| names.iter().flatten().any(|name| *name == word))
+    });
+    params
+        .iter()
+        .zip(&names)
+        .enumerate()
+        .filter(|(_, (_, name))| name.is_none())
+        .find_map(|(position, ((param_loc, _), _))| {
+            remaining.next().is_none().then(|| {
+                (
+                    ViolationError::MissingUnnamedComment {
+                        tag: tag.clone(),
+                        position: position + 1,
+                    },
+                    *param_loc,
+                )
+            })
+        })
+}
+
+/// Checks the unnamed parameters of a function against the policy, before they are matched
+/// with their `tag` comments by [`check_parameter_docs`].
+/// Returns the error and the location of the first unnamed parameter that is not allowed.
+pub(crate) fn check_unnamed_params(
+    func: &FunctionDefinition,
+    params: &ParameterList,
+    tag: &CommentTag,
+    policy: UnnamedParams,
+) -> Option<(ViolationError, Loc)> {
+    let public = matches!(
+        Visibility::of(func),
+        Some(Visibility::External | Visibility::Public)
+    );
+
+    params.iter().enumerate().find_map(|(i, (loc, param))| {
+        let name = param.as_ref().and_then(|param| param.name.as_ref());
+        let (tag, position) = (tag.clone(), i + 1);
+        let error = match policy {
+            UnnamedParams::CustomName if name.is_none() => {
+                ViolationError::UnnamedWithoutCustomName { tag, position }
+            }
+            // Names set with `@custom:name` are not part of the ABI
+            UnnamedParams::Forbid
+                if public && name.is_none_or(|name| name.loc == Loc::Implicit) =>
+            {
+                ViolationError::UnnamedInPublicApi { tag, position }
+            }
+            _ => return None,
+        };
+        Some((error, *loc))
+    })
 }
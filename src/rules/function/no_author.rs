@@ -6,7 +6,8 @@ crate::no_comment_rule!(
     NoAuthor,
     FunctionDefinition,
     Author,
-    "Functions must not have an author comment."
+    "Functions must not have an author comment.",
+    super::is_not_modifier
 );
 
 #[cfg(test)]
@@ -55,4 +56,15 @@ mod tests {
         "@author",
         FunctionDefinition
     );
+
+    test_no_author!(
+        modifier_no_violation,
+        r"
+        contract Test {
+            /// @author Some comment
+            modifier onlyOwner() { _; }
+        }
+        ",
+        |_| None
+    );
 }
@@ -6,7 +6,8 @@ crate::no_comment_rule!(
     NoTitle,
     FunctionDefinition,
     Title,
-    "Functions must not have a title comment."
+    "Functions must not have a title comment.",
+    super::is_not_modifier
 );
 
 #[cfg(test)]
@@ -55,4 +56,15 @@ mod tests {
         "@title",
         FunctionDefinition
     );
+
+    test_no_title!(
+        modifier_no_violation,
+        r"
+        contract Test {
+            /// @title Some comment
+            modifier onlyOwner() { _; }
+        }
+        ",
+        |_| None
+    );
 }
@@ -4,7 +4,7 @@
 use solang_parser::pt::FunctionDefinition;
 
 use crate::{
-    parser::{CommentsRef, ParseItem},
+    parser::{CommentTag, CommentsRef, ParseItem},
     rules::violation_error::ViolationError,
 };
 
@@ -24,26 +24,28 @@ impl Rule for OnlyInheritdoc {
         func: &FunctionDefinition,
         comments: &CommentsRef,
     ) -> Option<Violation> {
-        if comments.find_inheritdoc_base().is_some() {
-            return match comments.len() {
-                0 => unreachable!("Inheritdoc comment should have been found"),
-                1 => None,
-                _ => Some(Violation::new(
+        comments.find_inheritdoc_base()?;
+
+        // Reported at the first other comment
+        comments
+            .iter()
+            .find(|comment| comment.tag != CommentTag::Inheritdoc)
+            .or_else(|| comments.get(1))
+            .map(|comment| {
+                Violation::new(
                     Self::NAME,
                     Self::DESCRIPTION,
                     ViolationError::OnlyInheritdoc,
-                    func.loc,
-                )),
-            };
-        }
-        None
+                    comment.loc_or(func.loc),
+                )
+            })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{CommentsRef, FunctionDefinition, OnlyInheritdoc, Rule, Violation, ViolationError};
-    use crate::parser::Parser;
+    use super::{CommentsRef, OnlyInheritdoc, Rule, Violation, ViolationError};
+    use crate::{parser::Parser, rules::macros::text_loc};
     use forge_fmt::Visitable;
     use solang_parser::parse;
 
@@ -65,7 +67,7 @@ mod tests {
                 let func = child.as_function().unwrap();
                 let comments = CommentsRef::from(&child.comments);
 
-                let expected = $expected(func);
+                let expected = $expected(func, $source);
 
                 assert_eq!(
                     OnlyInheritdoc::check(Some(parent), func, &comments),
@@ -83,7 +85,7 @@ mod tests {
             function test() public {}
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_only_inheritdoc!(
@@ -93,7 +95,7 @@ mod tests {
             function test() external {}
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_only_inheritdoc!(
@@ -104,7 +106,7 @@ mod tests {
             function test() override {}
         }
         ",
-        |_| None
+        |_, _| None
     );
 
     test_only_inheritdoc!(
@@ -116,11 +118,11 @@ mod tests {
             function test() public {}
         }
         ",
-        |func: &FunctionDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             OnlyInheritdoc::NAME,
             OnlyInheritdoc::DESCRIPTION,
             ViolationError::OnlyInheritdoc,
-            func.loc
+            text_loc(src, "@notice A test function")
         ))
     );
 
@@ -135,11 +137,11 @@ mod tests {
             function test() public {}
         }
         ",
-        |func: &FunctionDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             OnlyInheritdoc::NAME,
             OnlyInheritdoc::DESCRIPTION,
             ViolationError::OnlyInheritdoc,
-            func.loc
+            text_loc(src, "@notice A test function")
         ))
     );
 
@@ -152,11 +154,11 @@ mod tests {
             function test() external {}
         }
         ",
-        |func: &FunctionDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             OnlyInheritdoc::NAME,
             OnlyInheritdoc::DESCRIPTION,
             ViolationError::OnlyInheritdoc,
-            func.loc
+            text_loc(src, "@notice A test function")
         ))
     );
 
@@ -169,11 +171,11 @@ mod tests {
             function test() override {}
         }
         ",
-        |func: &FunctionDefinition| Some(Violation::new(
+        |_, src| Some(Violation::new(
             OnlyInheritdoc::NAME,
             OnlyInheritdoc::DESCRIPTION,
             ViolationError::OnlyInheritdoc,
-            func.loc
+            text_loc(src, "@inheritdoc Base2")
         ))
     );
 }
@@ -0,0 +1,204 @@
+use solang_parser::pt::{FunctionAttribute, FunctionDefinition};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    project::signature::function_signature,
+    rules::{context::RuleContext, violation_error::ViolationError},
+};
+
+use super::super::{Rule, Violation};
+
+/// This rule requires that functions with an inheritdoc comment override a function of the base.
+///
+/// When the base can be resolved, it must declare a function with the same selector. Otherwise,
+/// the function must at least have an `override` attribute, since functions implementing an
+/// interface do not need one. Bases that are not bases of the contract are left to
+/// [`InvalidInheritdoc`](super::InvalidInheritdoc).
+pub struct UnmatchedInheritdoc;
+
+impl Rule for UnmatchedInheritdoc {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "UnmatchedInheritdoc";
+    const DESCRIPTION: &'static str =
+        "Function inheritdoc comments must be on an override of a function of the base.";
+
+    fn check(
+        parent: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        Self::check_with_context(&RuleContext::from_parent(parent), func, comments)
+    }
+
+    fn check_with_context(
+        ctx: &RuleContext,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let base = comments.find_inheritdoc_base()?;
+        let signature = function_signature(func)?;
+        if !ctx.is_inheritdoc_base(base) {
+            return None;
+        }
+
+        let error = match ctx.declares_member(base, &signature) {
+            Some(true) => return None,
+            Some(false) => ViolationError::NotInBase(base.to_owned()),
+            None if is_override(func) => return None,
+            None => ViolationError::NotAnOverride,
+        };
+
+        Some(Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            error,
+            func.loc,
+        ))
+    }
+}
+
+/// Returns `true` if the function has an `override` attribute.
+fn is_override(func: &FunctionDefinition) -> bool {
+    func.attributes
+        .iter()
+        .any(|attr| matches!(attr, FunctionAttribute::Override(..)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionDefinition, Rule, RuleContext, UnmatchedInheritdoc, Violation};
+    use crate::{parser::CommentsRef, project::SourceFile, rules::violation_error::ViolationError};
+    use std::path::PathBuf;
+
+    macro_rules! test_unmatchedinheritdoc {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let file = SourceFile::parse(PathBuf::new(), $source.to_owned()).unwrap();
+
+                let parent = file.items.last().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+                let ctx = RuleContext {
+                    parent: Some(parent),
+                    ..RuleContext::from_file(&file)
+                };
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    UnmatchedInheritdoc::check_with_context(&ctx, func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_unmatchedinheritdoc!(
+        no_inheritdoc_no_violation,
+        r"
+        contract Test {
+            /// @notice Some function
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        declared_in_base_no_violation,
+        r"
+        interface IFoo {
+            function test() external;
+        }
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        declared_in_indirect_base_no_violation,
+        r"
+        interface IFoo {
+            function test() external;
+        }
+        interface IBar is IFoo {}
+
+        contract Test is IBar {
+            /// @inheritdoc IBar
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        unknown_base_override_no_violation,
+        r"
+        import {IFoo} from './IFoo.sol';
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            function test() public override {}
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        unknown_base_not_override_violation,
+        r"
+        pragma solidity ^0.8.20;
+        import {IFoo} from './IFoo.sol';
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            UnmatchedInheritdoc::NAME,
+            UnmatchedInheritdoc::DESCRIPTION,
+            ViolationError::NotAnOverride,
+            func.loc
+        ))
+    );
+
+    test_unmatchedinheritdoc!(
+        not_in_base_violation,
+        r"
+        interface IFoo {
+            function test(uint256 value) external;
+        }
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            function test() public override {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            UnmatchedInheritdoc::NAME,
+            UnmatchedInheritdoc::DESCRIPTION,
+            ViolationError::NotInBase("IFoo".to_owned()),
+            func.loc
+        ))
+    );
+
+    test_unmatchedinheritdoc!(
+        not_a_base_no_violation,
+        r"
+        interface IFoo {}
+
+        contract Test {
+            /// @inheritdoc IFoo
+            function test() public {}
+        }
+        ",
+        |_| None
+    );
+}
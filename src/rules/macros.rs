@@ -1,9 +1,27 @@
 //! This module defines the macros for quickly defining some classes of rules.
 
+#[cfg(test)]
+use solang_parser::pt::Loc;
+
+/// Returns the location of the first occurrence of `text` in `src`, e.g. of the comment a
+/// violation is expected at.
+///
+/// # Panics
+/// Panics if `text` is not in `src`.
+#[cfg(test)]
+#[must_use]
+pub fn text_loc(src: &str, text: &str) -> Loc {
+    let start = src.find(text).expect("text not found in the source");
+    Loc::File(0, start, start + text.len())
+}
+
 /// This macro defines a rule that checks against a specific comment tag.
+///
+/// An optional predicate path can be passed as the last argument to restrict the rule to a subset
+/// of the target items (e.g. only modifiers among function definitions).
 #[macro_export]
 macro_rules! no_comment_rule {
-    ($rule_name:ident, $parse_item:ty, $comment_variant:ident, $description:expr) => {
+    ($rule_name:ident, $parse_item:ty, $comment_variant:ident, $description:expr $(, $filter:path)?) => {
         #[doc = $description]
         pub struct $rule_name;
 
@@ -17,29 +35,36 @@ macro_rules! no_comment_rule {
                 item: &$parse_item,
                 comments: &$crate::parser::CommentsRef,
             ) -> Option<$crate::rules::Violation> {
-                if !comments
+                $(
+                    if !$filter(item) {
+                        return None;
+                    }
+                )?
+                // Reported at the first comment with the tag
+                comments
                     .include_tag($crate::parser::CommentTag::$comment_variant)
-                    .is_empty()
-                {
-                    return Some($crate::rules::Violation::new(
-                        Self::NAME,
-                        Self::DESCRIPTION,
-                        $crate::rules::violation_error::ViolationError::CommentNotAllowed(
-                            $crate::parser::CommentTag::$comment_variant,
-                        ),
-                        item.loc,
-                    ));
-                }
-                None
+                    .first()
+                    .map(|comment| {
+                        $crate::rules::Violation::new(
+                            Self::NAME,
+                            Self::DESCRIPTION,
+                            $crate::rules::violation_error::ViolationError::CommentNotAllowed(
+                                $crate::parser::CommentTag::$comment_variant,
+                            ),
+                            comment.loc_or(item.loc),
+                        )
+                    })
             }
         }
     };
 }
 
 /// This macro defines a rule that enforces the presence of a specific comment tag.
+///
+/// Accepts the same optional predicate path as [`no_comment_rule`].
 #[macro_export]
 macro_rules! missing_comment_rule {
-    ($rule_name:ident, $parse_item:ty, $comment_variant:ident, $description:expr) => {
+    ($rule_name:ident, $parse_item:ty, $comment_variant:ident, $description:expr $(, $filter:path)?) => {
         #[doc = $description]
         pub struct $rule_name;
 
@@ -53,6 +78,11 @@ macro_rules! missing_comment_rule {
                 item: &$parse_item,
                 comments: &$crate::parser::CommentsRef,
             ) -> Option<$crate::rules::Violation> {
+                $(
+                    if !$filter(item) {
+                        return None;
+                    }
+                )?
                 if comments
                     .include_tag($crate::parser::CommentTag::$comment_variant)
                     .is_empty()
@@ -73,9 +103,11 @@ macro_rules! missing_comment_rule {
 }
 
 /// This macro defines a rule that enforces a maximum of one comment with a specific tag.
+///
+/// Accepts the same optional predicate path as [`no_comment_rule`].
 #[macro_export]
 macro_rules! too_many_comments_rule {
-    ($rule_name:ident, $parse_item:ty, $comment_variant:ident, $description:expr) => {
+    ($rule_name:ident, $parse_item:ty, $comment_variant:ident, $description:expr $(, $filter:path)?) => {
         #[doc = $description]
         pub struct $rule_name;
 
@@ -89,21 +121,25 @@ macro_rules! too_many_comments_rule {
                 item: &$parse_item,
                 comments: &$crate::parser::CommentsRef,
             ) -> Option<$crate::rules::Violation> {
-                if comments
+                $(
+                    if !$filter(item) {
+                        return None;
+                    }
+                )?
+                // Reported at the first comment in excess
+                comments
                     .include_tag($crate::parser::CommentTag::$comment_variant)
-                    .len()
-                    > 1
-                {
-                    return Some($crate::rules::Violation::new(
-                        Self::NAME,
-                        Self::DESCRIPTION,
-                        $crate::rules::violation_error::ViolationError::TooManyComments(
-                            $crate::parser::CommentTag::$comment_variant,
-                        ),
-                        item.loc,
-                    ));
-                }
-                None
+                    .get(1)
+                    .map(|comment| {
+                        $crate::rules::Violation::new(
+                            Self::NAME,
+                            Self::DESCRIPTION,
+                            $crate::rules::violation_error::ViolationError::TooManyComments(
+                                $crate::parser::CommentTag::$comment_variant,
+                            ),
+                            comment.loc_or(item.loc),
+                        )
+                    })
             }
         }
     };
@@ -315,11 +351,21 @@ macro_rules! generate_no_comment_test_cases {
                 }}",
                 $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::CommentNotAllowed(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                interface Test {{
+                    /// {} Some comment
+                    {}
+                }}",
+                        $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some comment", $doc_tag)
+                )
             ))
         );
 
@@ -335,11 +381,22 @@ macro_rules! generate_no_comment_test_cases {
                 }}",
                 $doc_tag, $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::CommentNotAllowed(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                interface Test {{
+                    /// {} Some comment
+                    /// {} Some other
+                    {}
+                }}",
+                        $doc_tag, $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some comment", $doc_tag)
+                )
             ))
         );
 
@@ -355,11 +412,22 @@ macro_rules! generate_no_comment_test_cases {
                 }}",
                 $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::CommentNotAllowed(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                interface Test {{
+                    /// {} Some comment
+                    /// @custom:test Some comment
+                    {}
+                }}",
+                        $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some comment", $doc_tag)
+                )
             ))
         );
 
@@ -376,11 +444,23 @@ macro_rules! generate_no_comment_test_cases {
                 }}",
                 $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::CommentNotAllowed(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                interface Test {{
+                    /**
+                     * {} Some comment
+                     */
+                    {}
+                }}",
+                        $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some comment", $doc_tag)
+                )
             ))
         );
 
@@ -398,11 +478,24 @@ macro_rules! generate_no_comment_test_cases {
                 }}",
                 $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::CommentNotAllowed(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                interface Test {{
+                    /**
+                     * {} Some comment
+                     * @custom:test Some comment
+                     */
+                    {}
+                }}",
+                        $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some comment", $doc_tag)
+                )
             ))
         );
 
@@ -420,11 +513,24 @@ macro_rules! generate_no_comment_test_cases {
                 }}",
                 $doc_tag, $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::CommentNotAllowed(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                interface Test {{
+                    /**
+                     * {} Some comment
+                     * {} Some other
+                     */
+                    {}
+                }}",
+                        $doc_tag, $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some comment", $doc_tag)
+                )
             ))
         );
     };
@@ -542,11 +648,22 @@ macro_rules! generate_too_many_comment_test_cases {
                 }}",
                 $doc_tag, $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::TooManyComments(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                contract Test {{
+                    /// {} Some comment
+                    /// {} Some other
+                    {}
+                }}",
+                        $doc_tag, $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some other", $doc_tag)
+                )
             ))
         );
 
@@ -564,11 +681,24 @@ macro_rules! generate_too_many_comment_test_cases {
                 }}",
                 $doc_tag, $doc_tag, $struct_body
             ),
-            |item: &$parse_item| Some(Violation::new(
+            |_: &$parse_item| Some(Violation::new(
                 $rule_name::NAME,
                 $rule_name::DESCRIPTION,
                 ViolationError::TooManyComments(CommentTag::$comment_variant),
-                item.loc
+                $crate::rules::macros::text_loc(
+                    &format!(
+                        r"
+                contract Test {{
+                    /**
+                     * {} Some comment
+                     * {} Some other
+                     */
+                    {}
+                }}",
+                        $doc_tag, $doc_tag, $struct_body
+                    ),
+                    &format!("{} Some other", $doc_tag)
+                )
             ))
         );
     };
@@ -1,21 +1,41 @@
 //! This module defines the rules for the natlint linter.
 
-use std::any::{Any, TypeId};
+use std::{
+    any::{Any, TypeId},
+    marker::PhantomData,
+};
 
+use context::RuleContext;
+use fix::Fix;
 use solang_parser::pt::Loc;
 use violation_error::ViolationError;
 
-use crate::parser::{CommentsRef, ParseItem};
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    project::SourceFile,
+};
 
+pub mod context;
+pub mod fix;
 pub mod macros;
+pub mod pattern;
+pub mod scope;
+pub mod tag_name;
 pub mod violation_error;
 
+pub mod comment;
+pub mod constructor;
 pub mod contract;
+pub mod custom_rule;
+pub mod custom_tag;
 pub mod r#enum;
 pub mod error;
 pub mod event;
 pub mod function;
+pub mod modifier;
+pub mod special_function;
 pub mod r#struct;
+pub mod tag_pattern;
 pub mod r#type;
 pub mod variable;
 
@@ -30,6 +50,8 @@ pub struct Violation {
     pub error: ViolationError,
     /// The location of the violation.
     pub loc: Loc,
+    /// An automatic fix of the violation, if any.
+    pub fix: Option<Fix>,
 }
 
 /// A trait for defining a rule that checks a specific Solidity construct.
@@ -48,6 +70,33 @@ pub trait Rule {
         item: &Self::Target,
         comments: &CommentsRef,
     ) -> Option<Violation>;
+
+    /// Check the construct for violations of this rule, with access to its [`RuleContext`]: the
+    /// ancestors of the construct, the rest of the file (e.g. its pragmas and source text) and the
+    /// project. Rules that only need the parent item rely on the default, which calls
+    /// [`Rule::check`].
+    fn check_with_context(
+        ctx: &RuleContext,
+        item: &Self::Target,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        Self::check(ctx.parent, item, comments)
+    }
+}
+
+/// A trait for defining a rule that checks a whole file rather than a single item, e.g. the doc
+/// comments that are not attached to any item.
+///
+/// The [`SourceFile`] gives access to the parse tree, all the comments parsed by solang, the item
+/// tree and the source text. File rules are boxed as a [`DynRule`] with a [`FileRuleAdapter`].
+pub trait FileRule {
+    /// The name of the rule.
+    const NAME: &'static str;
+    /// A description of the rule.
+    const DESCRIPTION: &'static str;
+
+    /// Check the file for violations of this rule.
+    fn check(ctx: &RuleContext, file: &SourceFile) -> Vec<Violation>;
 }
 
 /// A dynamic version of the [`Rule`] trait.
@@ -61,10 +110,26 @@ pub trait DynRule {
     /// Check the construct for violations of this rule.
     fn check_dyn(
         &self,
-        parent: Option<&ParseItem>,
+        ctx: &RuleContext,
         item: &dyn Any,
         comments: &CommentsRef,
     ) -> Option<Violation>;
+    /// Check the construct for all the violations of this rule, for the rules that report several
+    /// per construct, e.g. one per return variable. Other rules rely on the default, which reports
+    /// the violation of [`DynRule::check_dyn`].
+    fn check_all(
+        &self,
+        ctx: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Vec<Violation> {
+        self.check_dyn(ctx, item, comments).into_iter().collect()
+    }
+    /// Check the whole file for violations of this rule, for the rules targeting [`SourceFile`].
+    /// Other rules rely on the default, which reports nothing.
+    fn check_file(&self, _ctx: &RuleContext, _file: &SourceFile) -> Vec<Violation> {
+        vec![]
+    }
 }
 
 impl Violation {
@@ -81,8 +146,16 @@ impl Violation {
             rule_description,
             error,
             loc,
+            fix: None,
         }
     }
+
+    /// Attach an automatic fix to the violation.
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
 }
 
 impl<R: Rule> DynRule for R
@@ -103,13 +176,52 @@ where
 
     fn check_dyn(
         &self,
-        parent: Option<&ParseItem>,
+        ctx: &RuleContext,
         item: &dyn Any,
         comments: &CommentsRef,
     ) -> Option<Violation> {
         let item = item
             .downcast_ref::<R::Target>()
             .expect("Item type mismatch");
-        R::check(parent, item, comments)
+        R::check_with_context(ctx, item, comments)
+    }
+}
+
+/// Boxes a [`FileRule`] as a [`DynRule`], which is implemented for every [`Rule`] already.
+pub struct FileRuleAdapter<R>(PhantomData<R>);
+
+impl<R: FileRule> FileRuleAdapter<R> {
+    /// Create the adapter of the file rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: FileRule> Default for FileRuleAdapter<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: FileRule> DynRule for FileRuleAdapter<R> {
+    fn name(&self) -> &'static str {
+        R::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        R::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<SourceFile>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, _: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        None
+    }
+
+    fn check_file(&self, ctx: &RuleContext, file: &SourceFile) -> Vec<Violation> {
+        R::check(ctx, file)
     }
 }
@@ -0,0 +1,162 @@
+//! Virtual modifiers without `override` are base declarations with nothing to inherit from, so
+//! only overriding modifiers are required to carry an inheritdoc comment.
+
+use solang_parser::pt::{ContractTy, FunctionAttribute, FunctionDefinition};
+
+use crate::{
+    parser::{CommentTag, CommentsRef, ParseItem},
+    rules::violation_error::ViolationError,
+};
+
+use super::super::{Rule, Violation};
+
+/// This rule requires that all overriding modifiers have an inheritdoc comment.
+pub struct MissingInheritdoc;
+
+impl Rule for MissingInheritdoc {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "MissingInheritdoc";
+    const DESCRIPTION: &'static str = "Override modifiers must have an inheritdoc comment.";
+
+    fn check(
+        parent: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        if !super::is_modifier(func) {
+            return None;
+        }
+
+        // Parent must be a contract, not an interface or library
+        match parent?.as_contract()?.ty {
+            ContractTy::Interface(_) | ContractTy::Library(_) => return None,
+            ContractTy::Contract(_) | ContractTy::Abstract(_) => (),
+        }
+
+        // Modifier must be an override
+        func.attributes
+            .iter()
+            .find(|attr| matches!(attr, FunctionAttribute::Override(..)))?;
+
+        // Modifier must have an inheritdoc comment
+        if comments.include_tag(CommentTag::Inheritdoc).is_empty() {
+            return Some(Violation::new(
+                Self::NAME,
+                Self::DESCRIPTION,
+                ViolationError::MissingComment(CommentTag::Inheritdoc),
+                func.loc,
+            ));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CommentTag, CommentsRef, FunctionDefinition, MissingInheritdoc, Rule, Violation,
+        ViolationError,
+    };
+    use crate::{generate_missing_comment_test_cases, parser::Parser};
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_missinginheritdoc {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    MissingInheritdoc::check(Some(parent), func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    mod override_test {
+        use super::*;
+
+        generate_missing_comment_test_cases!(
+            Inheritdoc,
+            test_missinginheritdoc,
+            MissingInheritdoc,
+            r"
+                modifier onlyOwner() override { _; }
+            ",
+            "@inheritdoc",
+            FunctionDefinition
+        );
+    }
+
+    mod virtual_override_test {
+        use super::*;
+
+        generate_missing_comment_test_cases!(
+            Inheritdoc,
+            test_missinginheritdoc,
+            MissingInheritdoc,
+            r"
+                modifier onlyOwner() virtual override { _; }
+            ",
+            "@inheritdoc",
+            FunctionDefinition
+        );
+    }
+
+    test_missinginheritdoc!(
+        virtual_no_violation,
+        r"
+        abstract contract Test {
+            modifier onlyOwner() virtual;
+        }
+        ",
+        |_| None
+    );
+
+    test_missinginheritdoc!(
+        plain_no_violation,
+        r"
+        contract Test {
+            modifier onlyOwner() { _; }
+        }
+        ",
+        |_| None
+    );
+
+    test_missinginheritdoc!(
+        library_no_violation,
+        r"
+        library Test {
+            modifier onlyOwner() override { _; }
+        }
+        ",
+        |_| None
+    );
+
+    test_missinginheritdoc!(
+        function_no_violation,
+        r"
+        contract Test {
+            function test() public override {}
+        }
+        ",
+        |_| None
+    );
+}
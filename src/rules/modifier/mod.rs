@@ -0,0 +1,105 @@
+//! This module defines the rules for modifier items in the natlint linter.
+//!
+//! Modifiers are parsed as function definitions, so these rules share the `FunctionDefinition`
+//! target with the function rules and only act on `FunctionTy::Modifier`.
+
+use solang_parser::pt::{FunctionDefinition, FunctionTy};
+
+mod missing_inheritdoc;
+mod missing_notice;
+mod missing_params;
+mod no_author;
+mod no_return;
+mod no_title;
+
+// The rules are exported here:
+pub use missing_inheritdoc::MissingInheritdoc;
+pub use missing_notice::MissingNotice;
+pub use missing_params::MissingParams;
+pub use no_author::NoAuthor;
+pub use no_return::NoReturn;
+pub use no_title::NoTitle;
+
+/// Configuration for modifier rules in the linter.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[allow(missing_docs, clippy::struct_excessive_bools)]
+pub struct ModifierRulesConfig {
+    #[serde(default = "ModifierRulesConfig::default_missing_inheritdoc")]
+    pub missing_inheritdoc: bool,
+    #[serde(default = "ModifierRulesConfig::default_missing_notice")]
+    pub missing_notice: bool,
+    #[serde(default = "ModifierRulesConfig::default_missing_params")]
+    pub missing_params: bool,
+    #[serde(default = "ModifierRulesConfig::default_no_author")]
+    pub no_author: bool,
+    #[serde(default = "ModifierRulesConfig::default_no_return")]
+    pub no_return: bool,
+    #[serde(default = "ModifierRulesConfig::default_no_title")]
+    pub no_title: bool,
+}
+
+impl Default for ModifierRulesConfig {
+    fn default() -> Self {
+        Self {
+            missing_inheritdoc: true,
+            missing_notice: true,
+            missing_params: true,
+            no_author: true,
+            no_return: true,
+            no_title: true,
+        }
+    }
+}
+
+impl ModifierRulesConfig {
+    /// Returns an iterator over the rules in the configuration.
+    #[must_use]
+    pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
+        let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
+
+        if self.missing_inheritdoc {
+            rules.push(Box::new(MissingInheritdoc));
+        }
+        if self.missing_notice {
+            rules.push(Box::new(MissingNotice));
+        }
+        if self.missing_params {
+            rules.push(Box::new(MissingParams));
+        }
+        if self.no_author {
+            rules.push(Box::new(NoAuthor));
+        }
+        if self.no_return {
+            rules.push(Box::new(NoReturn));
+        }
+        if self.no_title {
+            rules.push(Box::new(NoTitle));
+        }
+
+        rules
+    }
+
+    fn default_missing_inheritdoc() -> bool {
+        Self::default().missing_inheritdoc
+    }
+    fn default_missing_notice() -> bool {
+        Self::default().missing_notice
+    }
+    fn default_missing_params() -> bool {
+        Self::default().missing_params
+    }
+    fn default_no_author() -> bool {
+        Self::default().no_author
+    }
+    fn default_no_return() -> bool {
+        Self::default().no_return
+    }
+    fn default_no_title() -> bool {
+        Self::default().no_title
+    }
+}
+
+/// Returns `true` if the function definition is a modifier.
+pub(crate) const fn is_modifier(func: &FunctionDefinition) -> bool {
+    matches!(func.ty, FunctionTy::Modifier)
+}
@@ -0,0 +1,643 @@
+//! This module defines scoping for rules, restricting them to a subset of the parsed items.
+//!
+//! A [`ScopedRule`] wraps any [`DynRule`] and only forwards the check to it when the item is in
+//! [`Scope`], e.g. when it is declared inside an interface or is an external function.
+
+use std::{
+    any::{Any, TypeId},
+    collections::BTreeMap,
+};
+
+use serde::{Deserialize, Serialize};
+use solang_parser::pt::{
+    self, ContractDefinition, ContractTy, EnumDefinition, ErrorDefinition, EventDefinition,
+    FunctionAttribute, FunctionDefinition, FunctionTy, StructDefinition, TypeDefinition,
+    VariableAttribute, VariableDefinition,
+};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    project::SourceFile,
+};
+
+use super::{context::RuleContext, DynRule, Violation};
+
+/// The kind of contract an item is declared in.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum ContractKind {
+    Contract,
+    Interface,
+    Library,
+    Abstract,
+}
+
+impl From<&ContractTy> for ContractKind {
+    fn from(ty: &ContractTy) -> Self {
+        match ty {
+            ContractTy::Contract(_) => Self::Contract,
+            ContractTy::Interface(_) => Self::Interface,
+            ContractTy::Library(_) => Self::Library,
+            ContractTy::Abstract(_) => Self::Abstract,
+        }
+    }
+}
+
+impl ContractKind {
+    /// Returns the kind of contract the item belongs to.
+    /// Contracts belong to themselves, file-level items don't belong to any contract.
+    #[must_use]
+    pub fn of(parent: Option<&ParseItem>, item: &dyn Any) -> Option<Self> {
+        item.downcast_ref::<ContractDefinition>().map_or_else(
+            || {
+                parent?
+                    .as_contract()
+                    .map(|contract| Self::from(&contract.ty))
+            },
+            |contract| Some(Self::from(&contract.ty)),
+        )
+    }
+}
+
+/// The kind of an item, as in the name of its rule table.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum ItemKind {
+    Contract,
+    Function,
+    Modifier,
+    Constructor,
+    SpecialFunction,
+    Variable,
+    Event,
+    Error,
+    Struct,
+    Enum,
+    Type,
+}
+
+impl ItemKind {
+    /// Returns the kind of the item, or `None` if it is not a parsed item.
+    #[must_use]
+    pub fn of(item: &dyn Any) -> Option<Self> {
+        if let Some(func) = item.downcast_ref::<FunctionDefinition>() {
+            return Some(match func.ty {
+                FunctionTy::Function => Self::Function,
+                FunctionTy::Modifier => Self::Modifier,
+                FunctionTy::Constructor => Self::Constructor,
+                FunctionTy::Receive | FunctionTy::Fallback => Self::SpecialFunction,
+            });
+        }
+
+        let kinds: [(TypeId, Self); 7] = [
+            (TypeId::of::<ContractDefinition>(), Self::Contract),
+            (TypeId::of::<VariableDefinition>(), Self::Variable),
+            (TypeId::of::<EventDefinition>(), Self::Event),
+            (TypeId::of::<ErrorDefinition>(), Self::Error),
+            (TypeId::of::<StructDefinition>(), Self::Struct),
+            (TypeId::of::<EnumDefinition>(), Self::Enum),
+            (TypeId::of::<TypeDefinition>(), Self::Type),
+        ];
+        kinds
+            .into_iter()
+            .find(|(type_id, _)| *type_id == item.type_id())
+            .map(|(_, kind)| kind)
+    }
+}
+
+/// The visibility of a function or variable.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum Visibility {
+    External,
+    Public,
+    Internal,
+    Private,
+}
+
+impl From<&pt::Visibility> for Visibility {
+    fn from(visibility: &pt::Visibility) -> Self {
+        match visibility {
+            pt::Visibility::External(_) => Self::External,
+            pt::Visibility::Public(_) => Self::Public,
+            pt::Visibility::Internal(_) => Self::Internal,
+            pt::Visibility::Private(_) => Self::Private,
+        }
+    }
+}
+
+impl Visibility {
+    /// Returns the visibility of a function or variable.
+    /// Items without an explicit visibility are internal.
+    /// Returns `None` for any other item.
+    #[must_use]
+    pub fn of(item: &dyn Any) -> Option<Self> {
+        if let Some(func) = item.downcast_ref::<FunctionDefinition>() {
+            return Some(
+                func.attributes
+                    .iter()
+                    .find_map(|attr| match attr {
+                        FunctionAttribute::Visibility(visibility) => Some(visibility.into()),
+                        _ => None,
+                    })
+                    .unwrap_or(Self::Internal),
+            );
+        }
+
+        item.downcast_ref::<VariableDefinition>().map(|var| {
+            var.attrs
+                .iter()
+                .find_map(|attr| match attr {
+                    VariableAttribute::Visibility(visibility) => Some(visibility.into()),
+                    _ => None,
+                })
+                .unwrap_or(Self::Internal)
+        })
+    }
+}
+
+/// The state mutability of a function, or the mutability of a variable.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum Mutability {
+    Pure,
+    View,
+    Payable,
+    Nonpayable,
+    Constant,
+    Immutable,
+    Mutable,
+}
+
+impl Mutability {
+    /// Returns the mutability of a function or variable.
+    /// Functions without a state mutability are nonpayable and variables that are neither constant
+    /// nor immutable are mutable.
+    /// Returns `None` for any other item.
+    #[must_use]
+    pub fn of(item: &dyn Any) -> Option<Self> {
+        if let Some(func) = item.downcast_ref::<FunctionDefinition>() {
+            return Some(
+                func.attributes
+                    .iter()
+                    .find_map(|attr| match attr {
+                        FunctionAttribute::Mutability(pt::Mutability::Pure(_)) => Some(Self::Pure),
+                        FunctionAttribute::Mutability(
+                            pt::Mutability::View(_) | pt::Mutability::Constant(_),
+                        ) => Some(Self::View),
+                        FunctionAttribute::Mutability(pt::Mutability::Payable(_)) => {
+                            Some(Self::Payable)
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or(Self::Nonpayable),
+            );
+        }
+
+        item.downcast_ref::<VariableDefinition>().map(|var| {
+            var.attrs
+                .iter()
+                .find_map(|attr| match attr {
+                    VariableAttribute::Constant(_) => Some(Self::Constant),
+                    VariableAttribute::Immutable(_) => Some(Self::Immutable),
+                    _ => None,
+                })
+                .unwrap_or(Self::Mutable)
+        })
+    }
+}
+
+/// The set of items a rule applies to.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Scope {
+    /// If set, the rule only applies to items in these contract kinds.
+    pub kinds: Option<Vec<ContractKind>>,
+    /// The rule doesn't apply to items in these contract kinds.
+    pub excluded_kinds: Vec<ContractKind>,
+    /// If set, the rule only applies to these kinds of items.
+    pub items: Option<Vec<ItemKind>>,
+    /// If set, the rule only applies to functions and variables with these visibilities.
+    pub visibility: Option<Vec<Visibility>>,
+    /// If set, the rule only applies to functions and variables with these mutabilities.
+    pub mutability: Option<Vec<Mutability>>,
+}
+
+impl Scope {
+    /// Returns `true` if the item is in scope.
+    #[must_use]
+    pub fn contains(&self, parent: Option<&ParseItem>, item: &dyn Any) -> bool {
+        // Rules targeting a whole item are scoped like the rules targeting its node
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .map_or(item, |item| item.source.node().0);
+        let kind = ContractKind::of(parent, item);
+
+        if let Some(kinds) = &self.kinds {
+            if !kind.is_some_and(|kind| kinds.contains(&kind)) {
+                return false;
+            }
+        }
+        if kind.is_some_and(|kind| self.excluded_kinds.contains(&kind)) {
+            return false;
+        }
+
+        if let Some(items) = &self.items {
+            if !ItemKind::of(item).is_some_and(|kind| items.contains(&kind)) {
+                return false;
+            }
+        }
+
+        // Visibility and mutability filters only restrict the items that have them
+        if let (Some(allowed), Some(visibility)) = (&self.visibility, Visibility::of(item)) {
+            if !allowed.contains(&visibility) {
+                return false;
+            }
+        }
+        if let (Some(allowed), Some(mutability)) = (&self.mutability, Mutability::of(item)) {
+            if !allowed.contains(&mutability) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// A per-rule filter of the items it applies to, set in the `filters` sub-table of a rule table.
+///
+/// ```toml
+/// [function_rules.filters]
+/// missing_params = { visibility = ["external", "public"] }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuleFilter {
+    /// If set, the rule only applies to these kinds of items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ItemKind>>,
+    /// If set, the rule only applies to functions and variables with these visibilities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Vec<Visibility>>,
+    /// If set, the rule only applies to functions and variables with these mutabilities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutability: Option<Vec<Mutability>>,
+}
+
+impl From<&RuleFilter> for Scope {
+    fn from(filter: &RuleFilter) -> Self {
+        Self {
+            items: filter.items.clone(),
+            visibility: filter.visibility.clone(),
+            mutability: filter.mutability.clone(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Restricts the rules to the items matching their filter, if any.
+/// The filters are keyed by the rule's config key, e.g. `missing_params` for `MissingParams`.
+#[must_use]
+pub fn apply_filters(
+    rules: Vec<Box<dyn DynRule>>,
+    filters: &BTreeMap<String, RuleFilter>,
+) -> Vec<Box<dyn DynRule>> {
+    rules
+        .into_iter()
+        .map(|rule| match filters.get(&config_key(rule.name())) {
+            Some(filter) => Box::new(ScopedRule::new(rule, filter.into())),
+            None => rule,
+        })
+        .collect()
+}
+
+/// Converts a rule name to its config key, e.g. `MissingParams` to `missing_params`.
+fn config_key(rule_name: &str) -> String {
+    let mut key = String::with_capacity(rule_name.len() + 4);
+    for (i, c) in rule_name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            key.push('_');
+        }
+        key.push(c.to_ascii_lowercase());
+    }
+    key
+}
+
+/// A rule that is only checked against the items in its [`Scope`].
+pub struct ScopedRule {
+    rule: Box<dyn DynRule>,
+    scope: Scope,
+}
+
+impl ScopedRule {
+    /// Wraps the rule so that it only applies to the items in scope.
+    #[must_use]
+    pub fn new(rule: Box<dyn DynRule>, scope: Scope) -> Self {
+        Self { rule, scope }
+    }
+}
+
+impl DynRule for ScopedRule {
+    fn name(&self) -> &'static str {
+        self.rule.name()
+    }
+
+    fn description(&self) -> &'static str {
+        self.rule.description()
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        self.rule.target_type_id()
+    }
+
+    fn check_dyn(
+        &self,
+        ctx: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        if !self.scope.contains(ctx.parent, item) {
+            return None;
+        }
+
+        self.rule.check_dyn(ctx, item, comments)
+    }
+
+    fn check_all(
+        &self,
+        ctx: &RuleContext,
+        item: &dyn Any,
+        comments: &CommentsRef,
+    ) -> Vec<Violation> {
+        if !self.scope.contains(ctx.parent, item) {
+            return vec![];
+        }
+
+        self.rule.check_all(ctx, item, comments)
+    }
+
+    /// File rules are not scoped by item: they run once per file, with the rule tables that are
+    /// not restricted to a contract kind.
+    fn check_file(&self, ctx: &RuleContext, file: &SourceFile) -> Vec<Violation> {
+        if self.scope.kinds.is_some() {
+            return vec![];
+        }
+
+        self.rule.check_file(ctx, file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        config_key, ContractKind, DynRule, Mutability, RuleContext, Scope, ScopedRule, Visibility,
+    };
+    use crate::{
+        parser::{CommentsRef, Parser},
+        rules::function::MissingNotice,
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_scope {
+        ($name:ident, $source:expr, $scope:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let rule = ScopedRule::new(Box::new(MissingNotice), $scope);
+
+                assert_eq!(
+                    rule.check_dyn(&RuleContext::from_parent(Some(parent)), func, &comments)
+                        .is_some(),
+                    $expected
+                );
+            }
+        };
+    }
+
+    test_scope!(
+        default_in_scope,
+        r"
+        interface Test {
+            function test() external;
+        }
+        ",
+        Scope::default(),
+        true
+    );
+
+    test_scope!(
+        kind_in_scope,
+        r"
+        interface Test {
+            function test() external;
+        }
+        ",
+        Scope {
+            kinds: Some(vec![ContractKind::Interface]),
+            ..Scope::default()
+        },
+        true
+    );
+
+    test_scope!(
+        kind_out_of_scope,
+        r"
+        library Test {
+            function test() internal {}
+        }
+        ",
+        Scope {
+            kinds: Some(vec![ContractKind::Interface]),
+            ..Scope::default()
+        },
+        false
+    );
+
+    test_scope!(
+        excluded_kind_out_of_scope,
+        r"
+        abstract contract Test {
+            function test() internal {}
+        }
+        ",
+        Scope {
+            excluded_kinds: vec![ContractKind::Abstract],
+            ..Scope::default()
+        },
+        false
+    );
+
+    test_scope!(
+        excluded_kind_in_scope,
+        r"
+        contract Test {
+            function test() internal {}
+        }
+        ",
+        Scope {
+            excluded_kinds: vec![ContractKind::Abstract],
+            ..Scope::default()
+        },
+        true
+    );
+
+    #[test]
+    fn file_level_item() {
+        let src = parse_source("function test() {}");
+
+        let item = src.items_ref().first().unwrap();
+        let func = item.as_function().unwrap();
+        let comments = CommentsRef::from(&item.comments);
+
+        let excluding = ScopedRule::new(
+            Box::new(MissingNotice),
+            Scope {
+                excluded_kinds: vec![ContractKind::Contract],
+                ..Scope::default()
+            },
+        );
+        assert!(excluding
+            .check_dyn(&RuleContext::from_parent(None), func, &comments)
+            .is_some());
+
+        let including = ScopedRule::new(
+            Box::new(MissingNotice),
+            Scope {
+                kinds: Some(vec![ContractKind::Contract]),
+                ..Scope::default()
+            },
+        );
+        assert!(including
+            .check_dyn(&RuleContext::from_parent(None), func, &comments)
+            .is_none());
+    }
+
+    test_scope!(
+        visibility_in_scope,
+        r"
+        contract Test {
+            function test() external {}
+        }
+        ",
+        Scope {
+            visibility: Some(vec![Visibility::External, Visibility::Public]),
+            ..Scope::default()
+        },
+        true
+    );
+
+    test_scope!(
+        visibility_out_of_scope,
+        r"
+        contract Test {
+            function test() private {}
+        }
+        ",
+        Scope {
+            visibility: Some(vec![Visibility::External, Visibility::Public]),
+            ..Scope::default()
+        },
+        false
+    );
+
+    test_scope!(
+        default_visibility_out_of_scope,
+        r"
+        library Test {
+            function test() {}
+        }
+        ",
+        Scope {
+            visibility: Some(vec![Visibility::Public]),
+            ..Scope::default()
+        },
+        false
+    );
+
+    test_scope!(
+        mutability_in_scope,
+        r"
+        contract Test {
+            function test() external view {}
+        }
+        ",
+        Scope {
+            mutability: Some(vec![Mutability::View, Mutability::Pure]),
+            ..Scope::default()
+        },
+        true
+    );
+
+    test_scope!(
+        mutability_out_of_scope,
+        r"
+        contract Test {
+            function test() external payable {}
+        }
+        ",
+        Scope {
+            mutability: Some(vec![Mutability::View, Mutability::Pure]),
+            ..Scope::default()
+        },
+        false
+    );
+
+    test_scope!(
+        nonpayable_in_scope,
+        r"
+        contract Test {
+            function test() external {}
+        }
+        ",
+        Scope {
+            mutability: Some(vec![Mutability::Nonpayable]),
+            ..Scope::default()
+        },
+        true
+    );
+
+    #[test]
+    fn variable_visibility_and_mutability() {
+        let src = parse_source(
+            r"
+            contract Test {
+                uint256 public constant A = 1;
+                uint256 b;
+            }
+            ",
+        );
+
+        let parent = src.items_ref().first().unwrap();
+        let a = parent.children[0].as_variable().unwrap();
+        let b = parent.children[1].as_variable().unwrap();
+
+        assert_eq!(Visibility::of(a), Some(Visibility::Public));
+        assert_eq!(Mutability::of(a), Some(Mutability::Constant));
+        assert_eq!(Visibility::of(b), Some(Visibility::Internal));
+        assert_eq!(Mutability::of(b), Some(Mutability::Mutable));
+        assert_eq!(Visibility::of(parent.as_contract().unwrap()), None);
+    }
+
+    #[test]
+    fn rule_name_to_config_key() {
+        assert_eq!(config_key("MissingParams"), "missing_params");
+        assert_eq!(config_key("TooManyInheritdoc"), "too_many_inheritdoc");
+        assert_eq!(config_key("NoTitle"), "no_title");
+    }
+}
@@ -0,0 +1,144 @@
+use solang_parser::pt::FunctionDefinition;
+
+use crate::parser::{CommentTag, CommentsRef, ParseItem};
+
+use super::super::{function::check_parameter_docs, Rule, Violation};
+
+/// This rule requires that receive and fallback functions have their parameters documented or
+/// have an inheritdoc comment.
+pub struct MissingParams;
+
+impl Rule for MissingParams {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "MissingParams";
+    const DESCRIPTION: &'static str = "Receive and fallback functions must have their parameters documented or have an inheritdoc comment.";
+
+    fn check(
+        _: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        if !super::is_special_function(func) {
+            return None;
+        }
+
+        // If the function has an inheritdoc comment, it is exempt from this rule
+        if comments.find_inheritdoc_base().is_some() {
+            return None;
+        }
+
+        // Function must have a parameter comment for each parameter
+        check_parameter_docs(&func.params, &CommentTag::Param, comments, func.loc)
+            .map(|(error, loc)| Violation::new(Self::NAME, Self::DESCRIPTION, error, loc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommentTag, CommentsRef, FunctionDefinition, MissingParams, Rule, Violation};
+    use crate::{parser::Parser, rules::violation_error::ViolationError};
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_missingparams {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    MissingParams::check(Some(parent), func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_missingparams!(
+        receive_no_violation,
+        r"
+        contract Test {
+            receive() external payable {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        fallback_no_violation,
+        r"
+        contract Test {
+            /// @param input The calldata
+            fallback(bytes calldata input) external returns (bytes memory) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        inheritdoc_no_violation,
+        r"
+        contract Test {
+            /// @inheritdoc Base
+            fallback(bytes calldata input) external override returns (bytes memory) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        function_no_violation,
+        r"
+        contract Test {
+            function test(uint256 a) public {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingparams!(
+        fallback_violation,
+        r"
+        contract Test {
+            /// @notice Forwards calls
+            fallback(bytes calldata input) external returns (bytes memory) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::MissingComment(CommentTag::Param),
+            func.loc
+        ))
+    );
+
+    test_missingparams!(
+        name_not_found_violation,
+        r"
+        contract Test {
+            /// @param data The calldata
+            fallback(bytes calldata input) external returns (bytes memory) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingParams::NAME,
+            MissingParams::DESCRIPTION,
+            ViolationError::missing_comment_for(CommentTag::Param, "input"),
+            func.params[0].0
+        ))
+    );
+}
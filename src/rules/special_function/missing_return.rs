@@ -0,0 +1,144 @@
+use solang_parser::pt::FunctionDefinition;
+
+use crate::parser::{CommentTag, CommentsRef, ParseItem};
+
+use super::super::{function::check_parameter_docs, Rule, Violation};
+
+/// This rule requires that receive and fallback functions have their return variables documented
+/// or have an inheritdoc comment.
+pub struct MissingReturn;
+
+impl Rule for MissingReturn {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "MissingReturn";
+    const DESCRIPTION: &'static str = "Receive and fallback functions must have their return variables documented or have an inheritdoc comment.";
+
+    fn check(
+        _: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        if !super::is_special_function(func) {
+            return None;
+        }
+
+        // If the function has an inheritdoc comment, it is exempt from this rule
+        if comments.find_inheritdoc_base().is_some() {
+            return None;
+        }
+
+        // Function must have a return comment for each return variable
+        check_parameter_docs(&func.returns, &CommentTag::Return, comments, func.loc)
+            .map(|(error, loc)| Violation::new(Self::NAME, Self::DESCRIPTION, error, loc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommentTag, CommentsRef, FunctionDefinition, MissingReturn, Rule, Violation};
+    use crate::{parser::Parser, rules::violation_error::ViolationError};
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_missingreturn {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let func = child.as_function().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected = $expected(func);
+
+                assert_eq!(
+                    MissingReturn::check(Some(parent), func, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_missingreturn!(
+        receive_no_violation,
+        r"
+        contract Test {
+            receive() external payable {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        fallback_no_violation,
+        r"
+        contract Test {
+            /// @return The returned data
+            fallback(bytes calldata) external returns (bytes memory) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        inheritdoc_no_violation,
+        r"
+        contract Test {
+            /// @inheritdoc Base
+            fallback(bytes calldata) external override returns (bytes memory) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        function_no_violation,
+        r"
+        contract Test {
+            function test() public returns (uint256) {}
+        }
+        ",
+        |_| None
+    );
+
+    test_missingreturn!(
+        fallback_violation,
+        r"
+        contract Test {
+            /// @notice Forwards calls
+            fallback(bytes calldata) external returns (bytes memory) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingReturn::NAME,
+            MissingReturn::DESCRIPTION,
+            ViolationError::MissingComment(CommentTag::Return),
+            func.loc
+        ))
+    );
+
+    test_missingreturn!(
+        named_violation,
+        r"
+        contract Test {
+            /// @return data The returned data
+            fallback(bytes calldata) external returns (bytes memory output) {}
+        }
+        ",
+        |func: &FunctionDefinition| Some(Violation::new(
+            MissingReturn::NAME,
+            MissingReturn::DESCRIPTION,
+            ViolationError::missing_comment_for(CommentTag::Return, "output"),
+            func.returns[0].0
+        ))
+    );
+}
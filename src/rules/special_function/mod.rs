@@ -0,0 +1,73 @@
+//! This module defines the rules for the special `receive` and `fallback` functions in the
+//! natlint linter.
+//!
+//! These are parsed as function definitions, so the rules share the `FunctionDefinition` target
+//! with the function rules and only act on `FunctionTy::Receive` and `FunctionTy::Fallback`.
+
+use solang_parser::pt::{FunctionDefinition, FunctionTy};
+
+mod missing_notice;
+mod missing_params;
+mod missing_return;
+
+// The rules are exported here:
+pub use missing_notice::MissingNotice;
+pub use missing_params::MissingParams;
+pub use missing_return::MissingReturn;
+
+/// Configuration for receive and fallback function rules in the linter.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[allow(missing_docs, clippy::struct_excessive_bools)]
+pub struct SpecialFunctionRulesConfig {
+    #[serde(default = "SpecialFunctionRulesConfig::default_missing_notice")]
+    pub missing_notice: bool,
+    #[serde(default = "SpecialFunctionRulesConfig::default_missing_params")]
+    pub missing_params: bool,
+    #[serde(default = "SpecialFunctionRulesConfig::default_missing_return")]
+    pub missing_return: bool,
+}
+
+impl Default for SpecialFunctionRulesConfig {
+    fn default() -> Self {
+        Self {
+            missing_notice: true,
+            missing_params: true,
+            missing_return: true,
+        }
+    }
+}
+
+impl SpecialFunctionRulesConfig {
+    /// Returns an iterator over the rules in the configuration.
+    #[must_use]
+    pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
+        let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
+
+        if self.missing_notice {
+            rules.push(Box::new(MissingNotice));
+        }
+        if self.missing_params {
+            rules.push(Box::new(MissingParams));
+        }
+        if self.missing_return {
+            rules.push(Box::new(MissingReturn));
+        }
+
+        rules
+    }
+
+    fn default_missing_notice() -> bool {
+        Self::default().missing_notice
+    }
+    fn default_missing_params() -> bool {
+        Self::default().missing_params
+    }
+    fn default_missing_return() -> bool {
+        Self::default().missing_return
+    }
+}
+
+/// Returns `true` if the function definition is a `receive` or `fallback` function.
+pub(crate) const fn is_special_function(func: &FunctionDefinition) -> bool {
+    matches!(func.ty, FunctionTy::Receive | FunctionTy::Fallback)
+}
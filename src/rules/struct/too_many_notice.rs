@@ -13,7 +13,7 @@ mod tests {
     use crate::{
         generate_too_many_comment_test_cases,
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{violation_error::ViolationError, Rule, Violation},
+        rules::{macros::text_loc, violation_error::ViolationError, Rule, Violation},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -59,9 +59,7 @@ mod tests {
         StructDefinition
     );
 
-    test_too_many_notice!(
-        no_tag_violation,
-        r"
+    const NO_TAG_VIOLATION_SOURCE: &str = r"
         contract Test {
             /// Some notice
             /// @notice b Some struct
@@ -69,12 +67,16 @@ mod tests {
                 uint256 a;
             }
         }
-        ",
-        |sct: &StructDefinition| Some(Violation::new(
+        ";
+
+    test_too_many_notice!(
+        no_tag_violation,
+        NO_TAG_VIOLATION_SOURCE,
+        |_: &StructDefinition| Some(Violation::new(
             TooManyNotice::NAME,
             TooManyNotice::DESCRIPTION,
             ViolationError::TooManyComments(CommentTag::Notice),
-            sct.loc
+            text_loc(NO_TAG_VIOLATION_SOURCE, "@notice b Some struct")
         )) // WARNING: solang parser and the natspec docs interpret no tags as a notice
     );
 }
@@ -0,0 +1,39 @@
+//! This module defines the validation of the tag names used in the configuration.
+//!
+//! Tag names like `notice` or `custom:security` are checked when the configuration is loaded, so
+//! that a typo is reported instead of silently disabling a check.
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::parser::CommentTag;
+
+/// Returns the tag named `name`, or an error naming it if it is not a known tag.
+fn parse<E: de::Error>(name: &str) -> Result<CommentTag, E> {
+    CommentTag::from_str(name).ok_or_else(|| {
+        E::custom(format!(
+            "unknown tag `{name}`, custom tags must be preceded by `custom:`"
+        ))
+    })
+}
+
+/// Deserialize a tag name, rejecting unknown tags.
+/// # Errors
+/// Returns an error if the value is not a string or not a known tag.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    parse::<D::Error>(&name)?;
+    Ok(name)
+}
+
+/// Deserialize a list of tag names, rejecting unknown tags.
+/// # Errors
+/// Returns an error if the value is not a list of strings or one of them is not a known tag.
+pub fn deserialize_all<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let names = Vec::<String>::deserialize(deserializer)?;
+    for name in &names {
+        parse::<D::Error>(name)?;
+    }
+    Ok(names)
+}
@@ -0,0 +1,285 @@
+//! This module defines the declarative pattern rules of the `[[patterns]]` configuration section,
+//! for the conventions of a project that do not warrant a rule of their own.
+//!
+//! ```toml
+//! [[patterns]]
+//! items = ["contract"]
+//! tag = "author"
+//! must_match = '^[A-Z].+ <.+@.+>$'
+//! message = "must be a name followed by an email, e.g. `Jane Doe <jane@example.com>`"
+//!
+//! [[patterns]]
+//! tag = "notice"
+//! must_not_match = "TODO"
+//! message = "must not contain a TODO"
+//! ```
+
+use std::any::{Any, TypeId};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{CommentTag, CommentsRef, ParseItem};
+
+use super::{
+    context::RuleContext, pattern::Pattern, scope::ItemKind, violation_error::ViolationError,
+    DynRule, Violation,
+};
+
+/// An entry of the `[[patterns]]` configuration section.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TagPatternConfig {
+    /// The kinds of items checked, or all of them if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ItemKind>>,
+    /// The tag checked, e.g. `author` or `custom:security-contact`.
+    /// Unknown tags are rejected when the configuration is loaded, like invalid patterns.
+    #[serde(deserialize_with = "super::tag_name::deserialize")]
+    pub tag: String,
+    /// A pattern the values of the tag must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub must_match: Option<Pattern>,
+    /// A pattern the values of the tag must not match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub must_not_match: Option<Pattern>,
+    /// Whether the values of the tag must be the name of the item, e.g. for a contract `@title`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub must_equal_name: bool,
+    /// The message reported when a value does not follow the pattern.
+    pub message: String,
+}
+
+impl TagPatternConfig {
+    /// Returns `true` if the value of a tag on the item follows the pattern.
+    fn is_followed(&self, value: &str, item: &ParseItem) -> bool {
+        self.must_match
+            .as_ref()
+            .is_none_or(|pattern| pattern.is_match(value))
+            && !self
+                .must_not_match
+                .as_ref()
+                .is_some_and(|pattern| pattern.is_match(value))
+            && (!self.must_equal_name || value == item.source.ident())
+    }
+}
+
+/// This rule requires that the values of tags follow the patterns of the `[[patterns]]`
+/// configuration section.
+pub struct TagPattern {
+    patterns: Vec<(CommentTag, TagPatternConfig)>,
+}
+
+impl TagPattern {
+    /// The name of the rule.
+    pub const NAME: &'static str = "TagPattern";
+    /// A description of the rule.
+    pub const DESCRIPTION: &'static str =
+        "Tag values must follow the patterns of the configuration.";
+
+    /// Create the rule from the entries of the `[[patterns]]` section.
+    /// The tags are checked when the configuration is loaded, so entries built with an invalid
+    /// tag are ignored, since they cannot match any comment.
+    #[must_use]
+    pub fn new(patterns: &[TagPatternConfig]) -> Self {
+        Self {
+            patterns: patterns
+                .iter()
+                .filter_map(|pattern| Some((CommentTag::from_str(&pattern.tag)?, pattern.clone())))
+                .collect(),
+        }
+    }
+
+    /// Check the comments of the item against the patterns.
+    #[must_use]
+    pub fn check(&self, item: &ParseItem) -> Option<Violation> {
+        let kind = ItemKind::of(item.source.node().0)?;
+
+        self.patterns
+            .iter()
+            .filter(|(_, pattern)| {
+                pattern
+                    .items
+                    .as_ref()
+                    .is_none_or(|items| items.contains(&kind))
+            })
+            .find_map(|(tag, pattern)| {
+                item.comments
+                    .iter()
+                    .filter(|comment| comment.tag == *tag)
+                    .find(|comment| !pattern.is_followed(comment.value.trim(), item))
+                    .map(|_| {
+                        Violation::new(
+                            Self::NAME,
+                            Self::DESCRIPTION,
+                            ViolationError::PatternMismatch {
+                                tag: tag.clone(),
+                                message: pattern.message.clone(),
+                            },
+                            item.source.loc(),
+                        )
+                    })
+            })
+    }
+}
+
+impl DynRule for TagPattern {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        Self::DESCRIPTION
+    }
+
+    fn target_type_id(&self) -> TypeId {
+        TypeId::of::<ParseItem>()
+    }
+
+    fn check_dyn(&self, _: &RuleContext, item: &dyn Any, _: &CommentsRef) -> Option<Violation> {
+        let item = item
+            .downcast_ref::<ParseItem>()
+            .expect("Item type mismatch");
+        self.check(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TagPattern, TagPatternConfig, Violation};
+    use crate::{
+        parser::{CommentTag, Parser},
+        rules::{pattern::Pattern, scope::ItemKind, violation_error::ViolationError},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    fn patterns() -> Vec<TagPatternConfig> {
+        vec![
+            TagPatternConfig {
+                items: Some(vec![ItemKind::Contract]),
+                tag: "author".to_owned(),
+                must_match: Some(Pattern::new(r"^[A-Z].+ <.+@.+>$").unwrap()),
+                must_not_match: None,
+                must_equal_name: false,
+                message: "must be a name and an email".to_owned(),
+            },
+            TagPatternConfig {
+                items: Some(vec![ItemKind::Contract]),
+                tag: "title".to_owned(),
+                must_match: None,
+                must_not_match: None,
+                must_equal_name: true,
+                message: "must be the contract name".to_owned(),
+            },
+            TagPatternConfig {
+                items: None,
+                tag: "notice".to_owned(),
+                must_match: None,
+                must_not_match: Some(Pattern::new("TODO").unwrap()),
+                must_equal_name: false,
+                message: "must not contain a TODO".to_owned(),
+            },
+        ]
+    }
+
+    macro_rules! test_tagpattern {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+                let item = src.items_ref().first().unwrap();
+
+                let rule = TagPattern::new(&patterns());
+                let expected = $expected(item.source.loc());
+
+                assert_eq!(rule.check(item), expected);
+            }
+        };
+    }
+
+    test_tagpattern!(
+        following_no_violation,
+        r"
+        /// @title Test
+        /// @author Jane Doe <jane@example.com>
+        /// @notice A test contract
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_tagpattern!(
+        no_tags_no_violation,
+        r"
+        contract Test {}
+        ",
+        |_| None
+    );
+
+    test_tagpattern!(
+        other_kind_no_violation,
+        r"
+        /// @author jane
+        event Test();
+        ",
+        |_| None
+    );
+
+    test_tagpattern!(
+        must_match_violation,
+        r"
+        /// @title Test
+        /// @author jane
+        contract Test {}
+        ",
+        |loc| Some(Violation::new(
+            TagPattern::NAME,
+            TagPattern::DESCRIPTION,
+            ViolationError::PatternMismatch {
+                tag: CommentTag::Author,
+                message: "must be a name and an email".to_owned(),
+            },
+            loc
+        ))
+    );
+
+    test_tagpattern!(
+        must_equal_name_violation,
+        r"
+        /// @title Token
+        contract Test {}
+        ",
+        |loc| Some(Violation::new(
+            TagPattern::NAME,
+            TagPattern::DESCRIPTION,
+            ViolationError::PatternMismatch {
+                tag: CommentTag::Title,
+                message: "must be the contract name".to_owned(),
+            },
+            loc
+        ))
+    );
+
+    test_tagpattern!(
+        must_not_match_violation,
+        r"
+        /// @notice TODO: document this
+        event Test();
+        ",
+        |loc| Some(Violation::new(
+            TagPattern::NAME,
+            TagPattern::DESCRIPTION,
+            ViolationError::PatternMismatch {
+                tag: CommentTag::Notice,
+                message: "must not contain a TODO".to_owned(),
+            },
+            loc
+        ))
+    );
+}
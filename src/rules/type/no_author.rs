@@ -0,0 +1,56 @@
+use solang_parser::pt::TypeDefinition;
+
+crate::no_comment_rule!(
+    NoAuthor,
+    TypeDefinition,
+    Author,
+    "User-defined value types must not have an author comment."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{NoAuthor, TypeDefinition};
+    use crate::{
+        generate_no_comment_test_cases,
+        parser::{CommentTag, CommentsRef, Parser},
+        rules::{violation_error::ViolationError, Rule, Violation},
+    };
+    use forge_fmt::Visitable;
+    use solang_parser::parse;
+
+    fn parse_source(src: &str) -> Parser {
+        let (mut source, comments) = parse(src, 0).expect("failed to parse source");
+        let mut doc = Parser::new(comments, src.to_owned());
+        source.visit(&mut doc).expect("failed to visit source");
+        doc
+    }
+
+    macro_rules! test_no_author {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let src = parse_source($source);
+
+                let parent = src.items_ref().first().unwrap();
+                let child = parent.children.first().unwrap();
+                let ty = child.as_type().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+
+                let expected = $expected(ty);
+
+                assert_eq!(NoAuthor::check(Some(parent), ty, &comments), expected);
+            }
+        };
+    }
+
+    generate_no_comment_test_cases!(
+        Author,
+        test_no_author,
+        NoAuthor,
+        r"
+            type Price is uint256;
+        ",
+        "@author",
+        TypeDefinition
+    );
+}
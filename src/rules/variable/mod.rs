@@ -1,5 +1,10 @@
 //! This module defines the rules for variable items in the natlint linter.
 
+use std::collections::BTreeMap;
+
+use super::scope::RuleFilter;
+
+mod invalid_inheritdoc;
 mod missing_inheritdoc;
 mod missing_notice;
 mod no_author;
@@ -8,8 +13,10 @@ mod no_return;
 mod no_title;
 mod too_many_inheritdoc;
 mod too_many_notice;
+mod unmatched_inheritdoc;
 
 // The rules are exported here:
+pub use invalid_inheritdoc::InvalidInheritdoc;
 pub use missing_inheritdoc::MissingInheritdoc;
 pub use missing_notice::MissingNotice;
 pub use no_author::NoAuthor;
@@ -18,11 +25,14 @@ pub use no_return::NoReturn;
 pub use no_title::NoTitle;
 pub use too_many_inheritdoc::TooManyInheritdoc;
 pub use too_many_notice::TooManyNotice;
+pub use unmatched_inheritdoc::UnmatchedInheritdoc;
 
 /// Configuration for variable rules in the linter.
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[allow(missing_docs, clippy::struct_excessive_bools)]
 pub struct VariableRulesConfig {
+    #[serde(default = "VariableRulesConfig::default_invalid_inheritdoc")]
+    pub invalid_inheritdoc: bool,
     #[serde(default = "VariableRulesConfig::default_missing_inheritdoc")]
     pub missing_inheritdoc: bool,
     #[serde(default = "VariableRulesConfig::default_missing_notice")]
@@ -39,11 +49,16 @@ pub struct VariableRulesConfig {
     pub too_many_inheritdoc: bool,
     #[serde(default = "VariableRulesConfig::default_too_many_notice")]
     pub too_many_notice: bool,
+    #[serde(default = "VariableRulesConfig::default_unmatched_inheritdoc")]
+    pub unmatched_inheritdoc: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub filters: BTreeMap<String, RuleFilter>,
 }
 
 impl Default for VariableRulesConfig {
     fn default() -> Self {
         Self {
+            invalid_inheritdoc: true,
             missing_inheritdoc: true,
             missing_notice: true,
             no_author: true,
@@ -52,16 +67,22 @@ impl Default for VariableRulesConfig {
             no_title: true,
             too_many_inheritdoc: true,
             too_many_notice: true,
+            unmatched_inheritdoc: true,
+            filters: BTreeMap::new(),
         }
     }
 }
 
 impl VariableRulesConfig {
     /// Returns an iterator over the rules in the configuration.
+    /// Rules with an entry in `filters` only apply to the matching items.
     #[must_use]
     pub fn rule_set(&self) -> Vec<Box<dyn super::DynRule>> {
         let mut rules: Vec<Box<dyn super::DynRule>> = vec![];
 
+        if self.invalid_inheritdoc {
+            rules.push(Box::new(InvalidInheritdoc));
+        }
         if self.missing_inheritdoc {
             rules.push(Box::new(MissingInheritdoc));
         }
@@ -86,10 +107,16 @@ impl VariableRulesConfig {
         if self.too_many_notice {
             rules.push(Box::new(TooManyNotice));
         }
+        if self.unmatched_inheritdoc {
+            rules.push(Box::new(UnmatchedInheritdoc));
+        }
 
-        rules
+        super::scope::apply_filters(rules, &self.filters)
     }
 
+    fn default_invalid_inheritdoc() -> bool {
+        Self::default().invalid_inheritdoc
+    }
     fn default_missing_inheritdoc() -> bool {
         Self::default().missing_inheritdoc
     }
@@ -114,4 +141,7 @@ impl VariableRulesConfig {
     fn default_too_many_notice() -> bool {
         Self::default().too_many_notice
     }
+    fn default_unmatched_inheritdoc() -> bool {
+        Self::default().unmatched_inheritdoc
+    }
 }
@@ -13,7 +13,7 @@ mod tests {
     use crate::{
         generate_too_many_comment_test_cases,
         parser::{CommentTag, CommentsRef, Parser},
-        rules::{Rule, Violation, ViolationError},
+        rules::{macros::text_loc, Rule, Violation, ViolationError},
     };
     use forge_fmt::Visitable;
     use solang_parser::parse;
@@ -57,20 +57,22 @@ mod tests {
             VariableDefinition
         );
 
-        test_too_many_notice!(
-            no_tag_violation,
-            r#"
+        const NO_TAG_VIOLATION_SOURCE: &str = r#"
             contract Test {
                 /// Some notice
                 /// @notice Some other
                 bytes32 public constant SOME_CONST = keccak256("SOME_CONST");
             }
-            "#,
-            |sct: &VariableDefinition| Some(Violation::new(
+            "#;
+
+        test_too_many_notice!(
+            no_tag_violation,
+            NO_TAG_VIOLATION_SOURCE,
+            |_: &VariableDefinition| Some(Violation::new(
                 TooManyNotice::NAME,
                 TooManyNotice::DESCRIPTION,
                 ViolationError::TooManyComments(CommentTag::Notice),
-                sct.loc
+                text_loc(NO_TAG_VIOLATION_SOURCE, "@notice Some other")
             )) // WARNING: solang parser and the natspec docs interpret no tags as a notice
         );
     }
@@ -89,20 +91,22 @@ mod tests {
             VariableDefinition
         );
 
-        test_too_many_notice!(
-            no_tag_violation,
-            r"
+        const NO_TAG_VIOLATION_SOURCE: &str = r"
             contract Test {
                 /// Some notice
                 /// @notice Some other
                 bytes32 public immutable SOME_IMMUT;
             }
-            ",
-            |sct: &VariableDefinition| Some(Violation::new(
+            ";
+
+        test_too_many_notice!(
+            no_tag_violation,
+            NO_TAG_VIOLATION_SOURCE,
+            |_: &VariableDefinition| Some(Violation::new(
                 TooManyNotice::NAME,
                 TooManyNotice::DESCRIPTION,
                 ViolationError::TooManyComments(CommentTag::Notice),
-                sct.loc
+                text_loc(NO_TAG_VIOLATION_SOURCE, "@notice Some other")
             )) // WARNING: solang parser and the natspec docs interpret no tags as a notice
         );
     }
@@ -121,20 +125,22 @@ mod tests {
             VariableDefinition
         );
 
-        test_too_many_notice!(
-            no_tag_violation,
-            r"
+        const NO_TAG_VIOLATION_SOURCE: &str = r"
             contract Test {
                 /// Some notice
                 /// @notice Some other
                 State private state;
             }
-            ",
-            |sct: &VariableDefinition| Some(Violation::new(
+            ";
+
+        test_too_many_notice!(
+            no_tag_violation,
+            NO_TAG_VIOLATION_SOURCE,
+            |_: &VariableDefinition| Some(Violation::new(
                 TooManyNotice::NAME,
                 TooManyNotice::DESCRIPTION,
                 ViolationError::TooManyComments(CommentTag::Notice),
-                sct.loc
+                text_loc(NO_TAG_VIOLATION_SOURCE, "@notice Some other")
             )) // WARNING: solang parser and the natspec docs interpret no tags as a notice
         );
     }
@@ -0,0 +1,206 @@
+use solang_parser::pt::{VariableAttribute, VariableDefinition, Visibility};
+
+use crate::{
+    parser::{CommentsRef, ParseItem},
+    project::signature::getter_signature,
+    rules::{context::RuleContext, violation_error::ViolationError},
+};
+
+use super::super::{Rule, Violation};
+
+/// This rule requires that variables with an inheritdoc comment override a function of the base.
+///
+/// Only the getter of a public variable can override a function. When the base can be resolved,
+/// it must declare a function with the same selector as the getter. Otherwise, the variable must at
+/// least have an `override` attribute. Bases that are not bases of the contract are left to
+/// [`InvalidInheritdoc`](super::InvalidInheritdoc).
+pub struct UnmatchedInheritdoc;
+
+impl Rule for UnmatchedInheritdoc {
+    type Target = VariableDefinition;
+    const NAME: &'static str = "UnmatchedInheritdoc";
+    const DESCRIPTION: &'static str =
+        "Variable inheritdoc comments must be on an override of a function of the base.";
+
+    fn check(
+        parent: Option<&ParseItem>,
+        var: &VariableDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        Self::check_with_context(&RuleContext::from_parent(parent), var, comments)
+    }
+
+    fn check_with_context(
+        ctx: &RuleContext,
+        var: &VariableDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let base = comments.find_inheritdoc_base()?;
+        let signature = getter_signature(var)?;
+        if !ctx.is_inheritdoc_base(base) {
+            return None;
+        }
+
+        let error = if is_public(var) {
+            match ctx.declares_member(base, &signature) {
+                Some(true) => return None,
+                Some(false) => ViolationError::NotInBase(base.to_owned()),
+                None if is_override(var) => return None,
+                None => ViolationError::NotAnOverride,
+            }
+        } else {
+            ViolationError::NotAnOverride
+        };
+
+        Some(Violation::new(
+            Self::NAME,
+            Self::DESCRIPTION,
+            error,
+            var.loc,
+        ))
+    }
+}
+
+/// Returns `true` if the variable is public, i.e. has a getter.
+fn is_public(var: &VariableDefinition) -> bool {
+    var.attrs
+        .iter()
+        .any(|attr| matches!(attr, VariableAttribute::Visibility(Visibility::Public(_))))
+}
+
+/// Returns `true` if the variable has an `override` attribute.
+fn is_override(var: &VariableDefinition) -> bool {
+    var.attrs
+        .iter()
+        .any(|attr| matches!(attr, VariableAttribute::Override(..)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rule, RuleContext, UnmatchedInheritdoc, VariableDefinition, Violation};
+    use crate::{parser::CommentsRef, project::SourceFile, rules::violation_error::ViolationError};
+    use std::path::PathBuf;
+
+    macro_rules! test_unmatchedinheritdoc {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let file = SourceFile::parse(PathBuf::new(), $source.to_owned()).unwrap();
+
+                let parent = file.items.last().unwrap();
+                let child = parent.children.first().unwrap();
+                let var = child.as_variable().unwrap();
+                let comments = CommentsRef::from(&child.comments);
+                let ctx = RuleContext {
+                    parent: Some(parent),
+                    ..RuleContext::from_file(&file)
+                };
+
+                let expected = $expected(var);
+
+                assert_eq!(
+                    UnmatchedInheritdoc::check_with_context(&ctx, var, &comments),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_unmatchedinheritdoc!(
+        no_inheritdoc_no_violation,
+        r"
+        contract Test {
+            /// @notice Some variable
+            uint256 public value;
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        declared_in_base_no_violation,
+        r"
+        interface IFoo {
+            function balances(address account) external view returns (uint256);
+        }
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            mapping(address => uint256) public balances;
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        unknown_base_override_no_violation,
+        r"
+        import {IFoo} from './IFoo.sol';
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            uint256 public override value;
+        }
+        ",
+        |_| None
+    );
+
+    test_unmatchedinheritdoc!(
+        unknown_base_not_override_violation,
+        r"
+        pragma solidity ^0.8.20;
+        import {IFoo} from './IFoo.sol';
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            uint256 public value;
+        }
+        ",
+        |var: &VariableDefinition| Some(Violation::new(
+            UnmatchedInheritdoc::NAME,
+            UnmatchedInheritdoc::DESCRIPTION,
+            ViolationError::NotAnOverride,
+            var.loc
+        ))
+    );
+
+    test_unmatchedinheritdoc!(
+        not_public_violation,
+        r"
+        interface IFoo {
+            function value() external view returns (uint256);
+        }
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            uint256 internal value;
+        }
+        ",
+        |var: &VariableDefinition| Some(Violation::new(
+            UnmatchedInheritdoc::NAME,
+            UnmatchedInheritdoc::DESCRIPTION,
+            ViolationError::NotAnOverride,
+            var.loc
+        ))
+    );
+
+    test_unmatchedinheritdoc!(
+        not_in_base_violation,
+        r"
+        interface IFoo {
+            function value() external view returns (uint256);
+        }
+
+        contract Test is IFoo {
+            /// @inheritdoc IFoo
+            uint256 public total;
+        }
+        ",
+        |var: &VariableDefinition| Some(Violation::new(
+            UnmatchedInheritdoc::NAME,
+            UnmatchedInheritdoc::DESCRIPTION,
+            ViolationError::NotInBase("IFoo".to_owned()),
+            var.loc
+        ))
+    );
+}
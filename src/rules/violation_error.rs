@@ -3,7 +3,7 @@
 
 use thiserror::Error;
 
-use crate::parser::CommentTag;
+use crate::parser::{CommentTag, DocStyle};
 
 /// An error that occurs when a rule is violated.
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -13,12 +13,70 @@ pub enum ViolationError {
     MissingComment(CommentTag),
     #[error("Too many {0} comments")]
     TooManyComments(CommentTag),
+    #[error("Expected at least {min} {tag} comments")]
+    TooFewComments { tag: CommentTag, min: usize },
     #[error("{0} comments are not allowed on this construct")]
     CommentNotAllowed(CommentTag),
     #[error("Missing a {tag} comment for `{name}`")]
     MissingCommentFor { tag: CommentTag, name: String },
+    #[error("Missing a {tag} comment for the unnamed {} #{position}", slot(.tag))]
+    MissingUnnamedComment { tag: CommentTag, position: usize },
+    #[error("The unnamed {} #{position} must be named with `@custom:name`", slot(.tag))]
+    UnnamedWithoutCustomName { tag: CommentTag, position: usize },
+    #[error("The {} #{position} of a public or external function must be named", slot(.tag))]
+    UnnamedInPublicApi { tag: CommentTag, position: usize },
+    #[error("Return comment #{position} documents `{name}`, which is return variable #{expected}")]
+    MisplacedReturn {
+        position: usize,
+        name: String,
+        expected: usize,
+    },
+    #[error("Return comment #{position} must start with the name `{name}`")]
+    ReturnNameMismatch { position: usize, name: String },
+    #[error("Empty {0} comment")]
+    EmptyComment(CommentTag),
+    #[error("Empty {tag} comment for `{name}`")]
+    EmptyCommentFor { tag: CommentTag, name: String },
+    #[error("Doc comment is not attached to any declaration")]
+    OrphanDocComment,
+    #[error("Doc comment must use the {0} style")]
+    DocCommentStyle(DocStyle),
+    #[error("Doc comments must not mix the `///` and `/** */` styles")]
+    MixedDocCommentStyles,
+    #[error("Lines of a `/** */` doc comment must start with a ` * ` aligned under the `/**`")]
+    MisalignedDocComment,
+    #[error("`@{tag}` must come before `@{before}`")]
+    TagOutOfOrder { tag: String, before: String },
+    #[error("Natspec in a plain comment is ignored by the compiler, use `///` or `/** */`")]
+    NatspecInPlainComment,
     #[error("Inheritdoc comment must be the only comment")]
     OnlyInheritdoc,
+    #[error("`{0}` is not a base of the contract")]
+    InvalidInheritdoc(String),
+    #[error("Inheritdoc comment on a member that does not override anything")]
+    NotAnOverride,
+    #[error("`{0}` does not declare this member")]
+    NotInBase(String),
+    #[error("`{name}` is named `{base_name}` in the inheritdoc base, so its {tag} comment is not inherited")]
+    MismatchedName {
+        tag: CommentTag,
+        name: String,
+        base_name: String,
+    },
+    #[error("Unknown tag `@{tag}`{}", did_you_mean(.suggestion.as_deref()))]
+    UnknownTag {
+        tag: String,
+        suggestion: Option<String>,
+    },
+    #[error("Malformed tag `@{tag}`{}", did_you_mean(.suggestion.as_deref()))]
+    MalformedTag {
+        tag: String,
+        suggestion: Option<String>,
+    },
+    #[error("The value of `@{tag}` must match `{pattern}`")]
+    InvalidTagValue { tag: String, pattern: String },
+    #[error("{tag} comment: {message}")]
+    PatternMismatch { tag: CommentTag, message: String },
     #[error("Error while parsing: {0}")]
     ParseError(String),
 }
@@ -33,9 +91,36 @@ impl ViolationError {
         }
     }
 
+    /// Create a new [`ViolationError::MismatchedName`] error.
+    #[must_use]
+    pub fn mismatched_name(
+        tag: CommentTag,
+        name: impl Into<String>,
+        base_name: impl Into<String>,
+    ) -> Self {
+        Self::MismatchedName {
+            tag,
+            name: name.into(),
+            base_name: base_name.into(),
+        }
+    }
+
     /// Create a new [`ViolationError::ParseError`] error.
     #[must_use]
     pub fn parse_error(msg: impl Into<String>) -> Self {
         Self::ParseError(msg.into())
     }
 }
+
+/// Returns what a `@param` or `@return` comment documents.
+const fn slot(tag: &CommentTag) -> &'static str {
+    match tag {
+        CommentTag::Return => "return variable",
+        _ => "parameter",
+    }
+}
+
+/// Formats the suggested correction of a tag, if any.
+fn did_you_mean(suggestion: Option<&str>) -> String {
+    suggestion.map_or_else(String::new, |tag| format!(", did you mean `@{tag}`?"))
+}
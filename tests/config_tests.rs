@@ -1,5 +1,8 @@
 //! Config test suite
-use natlint::config::Config;
+use natlint::config::{Config, ConfigError};
+use natlint::rules::comment::PreferredStyle;
+use natlint::rules::function::UnnamedParams;
+use natlint::rules::scope::{ContractKind, ItemKind, Mutability, Visibility};
 use std::path::Path;
 
 #[test]
@@ -60,6 +63,10 @@ fn test_parse_full_config() {
         full_config_result.err()
     );
     let full_config = full_config_result.unwrap();
+    assert!(
+        full_config.effective_docs,
+        "Full config effective_docs should be true"
+    );
     // Check a few rules to ensure they are all Some (since set to true)
     assert!(
         full_config.contract_rules.missing_author,
@@ -73,4 +80,294 @@ fn test_parse_full_config() {
         full_config.variable_rules.no_title,
         "Full config rule no_title should be true"
     );
+    assert!(
+        full_config.modifier_rules.missing_params,
+        "Full config rule missing_params should be true"
+    );
+    assert!(
+        full_config.constructor_rules.missing_params,
+        "Full config rule missing_params should be true"
+    );
+    assert!(
+        full_config.special_function_rules.missing_return,
+        "Full config rule missing_return should be true"
+    );
+    assert!(
+        full_config.type_rules.missing_notice,
+        "Full config rule missing_notice should be true"
+    );
+    assert_eq!(
+        full_config.comment_rules.style.prefer,
+        PreferredStyle::Block,
+        "Full config should prefer the block style"
+    );
+    assert_eq!(
+        full_config.function_rules.unnamed_params,
+        UnnamedParams::CustomName,
+        "Full config should require custom names for unnamed parameters"
+    );
+}
+
+#[test]
+fn test_parse_kind_config() {
+    // Test parsing a config file with contract kind overrides
+    let kind_path = Path::new("tests/data/kind_config.toml");
+    let kind_config = Config::from_file(kind_path).unwrap();
+    assert_eq!(
+        kind_config.custom_tags.allowed,
+        Some(vec!["security-contact".to_owned()])
+    );
+    assert!(
+        kind_config.contract.is_none() && kind_config.library.is_none(),
+        "Kinds without a section should not be overridden"
+    );
+
+    let interface = kind_config
+        .interface
+        .expect("interface section should be parsed");
+    // Check overridden rules
+    assert!(
+        interface.function_rules.missing_params,
+        "Interface config should override missing_params to true"
+    );
+    assert!(
+        !interface.contract_rules.missing_title,
+        "Interface config should override missing_title to false"
+    );
+    // Check rules inherited from the top-level tables
+    assert!(
+        !interface.function_rules.missing_return,
+        "Interface config should inherit missing_return from the top-level table"
+    );
+    assert!(
+        interface.contract_rules.missing_author,
+        "Interface config should inherit missing_author from the top-level table"
+    );
+    // Check a default rule that wasn't set anywhere
+    assert!(
+        interface.event_rules.missing_notice,
+        "Default rule missing_notice should be true in interface config"
+    );
+}
+
+#[test]
+fn test_reject_unknown_kind_section_keys() {
+    // Test that a kind section only accepts the rule tables
+    let invalid_kind_path = Path::new("tests/data/invalid_kind_config.toml");
+    let error = Config::from_file(invalid_kind_path).unwrap_err();
+    assert!(matches!(error, ConfigError::TomlParse(_)));
+    assert!(
+        error.to_string().contains("unknown field `custom_tags`"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn test_parse_filter_config() {
+    // Test parsing a config file with per-rule filters
+    let filter_path = Path::new("tests/data/filter_config.toml");
+    let filter_config = Config::from_file(filter_path).unwrap();
+
+    let filter = &filter_config.function_rules.filters["missing_params"];
+    assert_eq!(
+        filter.visibility,
+        Some(vec![Visibility::External, Visibility::Public])
+    );
+    assert_eq!(filter.mutability, None);
+
+    let filter = &filter_config.variable_rules.filters["missing_notice"];
+    assert_eq!(filter.visibility, Some(vec![Visibility::Public]));
+    assert_eq!(
+        filter.mutability,
+        Some(vec![Mutability::Constant, Mutability::Immutable])
+    );
+
+    let filter = &filter_config.comment_rules.filters["empty_tag"];
+    assert_eq!(
+        filter.items,
+        Some(vec![ItemKind::Function, ItemKind::Event])
+    );
+    assert_eq!(
+        filter_config.comment_rules.non_empty_tags,
+        ["notice", "param"]
+    );
+
+    // Rules without a filter are unaffected
+    assert!(!filter_config
+        .function_rules
+        .filters
+        .contains_key("missing_notice"));
+    assert!(
+        filter_config.function_rules.missing_params,
+        "Default rule missing_params should be true in filter config"
+    );
+}
+
+#[test]
+fn test_parse_custom_tags_config() {
+    // Test parsing a config file with a custom tag policy
+    let custom_tags_path = Path::new("tests/data/custom_tags_config.toml");
+    let custom_tags = Config::from_file(custom_tags_path).unwrap().custom_tags;
+
+    assert_eq!(
+        custom_tags.allowed,
+        Some(vec![
+            "security-contact".to_owned(),
+            "oz-upgrades-unsafe-allow".to_owned()
+        ])
+    );
+    assert_eq!(
+        custom_tags.required[&ItemKind::Contract],
+        ["security-contact"]
+    );
+    assert!(custom_tags.required[&ItemKind::Function].is_empty());
+    assert!(custom_tags.patterns["security-contact"].is_match("security@example.com"));
+    assert!(!custom_tags.patterns["security-contact"].is_match("The security team"));
+
+    // Without a policy, none of the custom tag rules are enabled
+    let empty_path = Path::new("tests/data/empty_config.toml");
+    let empty_config = Config::from_file(empty_path).unwrap();
+    assert!(empty_config.custom_tags.rule_set().is_empty());
+}
+
+#[test]
+fn test_parse_patterns_config() {
+    // Test parsing a config file with pattern rules
+    let patterns_path = Path::new("tests/data/patterns_config.toml");
+    let patterns = Config::from_file(patterns_path).unwrap().patterns;
+    assert_eq!(patterns.len(), 3);
+
+    assert_eq!(patterns[0].items, Some(vec![ItemKind::Contract]));
+    assert_eq!(patterns[0].tag, "author");
+    assert!(patterns[0]
+        .must_match
+        .as_ref()
+        .is_some_and(|pattern| pattern.is_match("Jane Doe <jane@example.com>")));
+    assert!(patterns[0].must_not_match.is_none() && !patterns[0].must_equal_name);
+
+    assert!(patterns[1].must_equal_name);
+    assert_eq!(patterns[1].message, "must be the contract name");
+
+    assert_eq!(patterns[2].items, None);
+    assert!(patterns[2]
+        .must_not_match
+        .as_ref()
+        .is_some_and(|pattern| pattern.is_match("TODO: document this")));
+}
+
+#[test]
+fn test_parse_custom_rules_config() {
+    // Test parsing a config file with custom rules
+    let custom_rules_path = Path::new("tests/data/custom_rules_config.toml");
+    let config = Config::from_file(custom_rules_path).unwrap();
+
+    let custom = &config.custom_rules.custom;
+    assert_eq!(custom.len(), 2);
+    assert_eq!(custom[0].name, "MissingExternalDev");
+    assert_eq!(custom[0].kinds, Some(vec![ContractKind::Contract]));
+    assert_eq!(custom[0].visibility, Some(vec![Visibility::External]));
+    assert_eq!((custom[0].at_least, custom[0].at_most), (Some(1), None));
+    assert_eq!(custom[1].items, Some(vec![ItemKind::Event]));
+    assert_eq!((custom[1].at_least, custom[1].at_most), (None, Some(0)));
+
+    // The custom rules are reported under their own names
+    let rules = config.rules();
+    for name in ["MissingExternalDev", "NoEventAuthor"] {
+        assert!(
+            rules.iter().any(|rule| rule.name() == name),
+            "Custom rule {name} should be enabled"
+        );
+    }
+}
+
+#[test]
+fn test_reject_unknown_non_empty_tags() {
+    // Test that a typo in the tags checked by `empty_tag` is reported instead of ignored
+    let unknown_tag_path = Path::new("tests/data/unknown_tag_config.toml");
+    let error = Config::from_file(unknown_tag_path).unwrap_err();
+    assert!(matches!(error, ConfigError::TomlParse(_)));
+    assert!(
+        error
+            .to_string()
+            .contains("unknown tag `secuirty`, custom tags must be preceded by `custom:`"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn test_reject_invalid_patterns() {
+    // Test that a pattern entry with a typo in its tag or regex is reported instead of ignored
+    let unknown_tag_path = Path::new("tests/data/unknown_pattern_tag_config.toml");
+    let error = Config::from_file(unknown_tag_path).unwrap_err();
+    assert!(matches!(error, ConfigError::TomlParse(_)));
+    assert!(
+        error.to_string().contains("unknown tag `autor`"),
+        "Unexpected error: {error}"
+    );
+
+    let invalid_pattern_path = Path::new("tests/data/invalid_pattern_config.toml");
+    let error = Config::from_file(invalid_pattern_path).unwrap_err();
+    assert!(matches!(error, ConfigError::TomlParse(_)));
+    assert!(
+        error.to_string().contains("must_match"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn test_reject_unknown_custom_rule_tag() {
+    // Test that a custom rule with a typo in its tag is reported instead of ignored
+    let unknown_tag_path = Path::new("tests/data/unknown_custom_rule_tag_config.toml");
+    let error = Config::from_file(unknown_tag_path).unwrap_err();
+    assert!(matches!(error, ConfigError::TomlParse(_)));
+    assert!(
+        error.to_string().contains("unknown tag `devv`"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn test_reject_invalid_custom_rules() {
+    // Test that custom rules that can never be met or take a built-in name are reported
+    let cases = [
+        (
+            "tests/data/invalid_custom_rule_count_config.toml",
+            "Invalid custom rule `CountedDev`: it sets neither `at_least` nor `at_most`",
+        ),
+        (
+            "tests/data/invalid_custom_rule_bounds_config.toml",
+            "Invalid custom rule `FewDevs`: `at_least` is greater than `at_most`",
+        ),
+        (
+            "tests/data/builtin_custom_rule_name_config.toml",
+            "Invalid custom rule `MissingNotice`: a built-in rule has the same name",
+        ),
+    ];
+    for (path, expected) in cases {
+        let error = Config::from_file(Path::new(path)).unwrap_err();
+        assert!(
+            matches!(error, ConfigError::InvalidCustomRule { .. }),
+            "Unexpected error: {error}"
+        );
+        assert_eq!(error.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_reject_unknown_filter() {
+    // Test that a filter with a typo in its rule is reported instead of ignored
+    let unknown_filter_path = Path::new("tests/data/unknown_filter_config.toml");
+    let error = Config::from_file(unknown_filter_path).unwrap_err();
+    assert!(
+        matches!(
+            &error,
+            ConfigError::UnknownFilter { table, rule }
+                if table == "interface.function_rules" && rule == "missing_param"
+        ),
+        "Unexpected error: {error}"
+    );
+    assert_eq!(
+        error.to_string(),
+        "Unknown rule `missing_param` in `[interface.function_rules.filters]`"
+    );
 }
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title TestConstructor
+/// @notice A contract with a constructor, receive and fallback functions
+contract TestConstructor {
+    /// @notice Deploys the contract
+    constructor(address owner, uint256 fee) {}
+
+    receive() external payable {}
+
+    /// @notice Forwards all calls
+    /// @param input The calldata
+    fallback(bytes calldata input) external returns (bytes memory) {}
+}
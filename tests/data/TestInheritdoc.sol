@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title IFoo
+/// @notice An interface to inherit the docs from
+interface IFoo {
+    /// @notice Returns the value
+    /// @return The value
+    function value() external view returns (uint256);
+}
+
+/// @title TestInheritdoc
+/// @notice A contract with inheritdoc comments
+contract TestInheritdoc is IFoo {
+    /// @inheritdoc IFoo
+    function value() external view returns (uint256) {}
+
+    /// @inheritdoc IBar
+    function other() external view returns (uint256) {}
+
+    /// @inheritdoc IFoo
+    function total() external view returns (uint256) {}
+}
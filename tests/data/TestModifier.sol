@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title TestModifier
+/// @notice A contract with modifiers
+contract TestModifier {
+    /// @notice Only the owner can call
+    modifier onlyOwner() { _; }
+
+    modifier onlyRole(bytes32 role) { _; }
+
+    /// @notice Some modifier
+    /// @author Someone
+    modifier whenNotPaused() { _; }
+}
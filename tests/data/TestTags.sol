@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title TestTags
+/// @notice A contract with invalid tags
+contract TestTags {
+    /// @notic Does something
+    function misspelled() internal {}
+
+    /// @notice Does something
+    /// @ dev Some details
+    function malformed() internal {}
+
+    /// @notice Does something
+    /// @param amount
+    function empty(uint256 amount) internal {}
+
+    /// @notice Documents nothing
+}
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title TestVisibility
+/// @notice A library with functions of different visibilities
+library TestVisibility {
+    /// @notice Adds two numbers
+    function add(uint256 a, uint256 b) external pure returns (uint256) {}
+
+    /// @notice Doubles a number
+    function double(uint256 a) internal pure returns (uint256) {}
+
+    function reset(uint256 a) private {}
+}
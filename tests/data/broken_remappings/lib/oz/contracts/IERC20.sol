@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title IERC20
+/// @notice The ERC20 interface
+interface IERC20 {}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import { IERC20 } from "@oz/IERC20.sol";
+
+/// @title Token
+/// @notice A token
+abstract contract Token is IERC20 {}
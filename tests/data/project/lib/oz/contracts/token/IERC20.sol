@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title IERC20
+/// @notice The ERC20 interface
+interface IERC20 {
+    /// @notice Returns the total supply
+    /// @return The total supply
+    function totalSupply() external view returns (uint256);
+
+    /// @notice Returns the balance of an account
+    function balanceOf(address account) external view returns (uint256);
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title Owned
+/// @notice A contract with an owner
+abstract contract Owned {
+    /// @notice Returns the owner
+    /// @return The owner
+    function owner() external view virtual returns (address);
+}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import { IToken } from "./IToken.sol";
+
+/// @title Child
+/// @notice A token documenting its own author
+contract Child is IToken {
+    /// @inheritdoc IToken
+    /// @author someone
+    function mint(uint256 amount) external {}
+}
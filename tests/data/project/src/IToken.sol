@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import { IERC20 } from "@oz/token/IERC20.sol";
+
+/// @title IToken
+/// @notice The token interface
+interface IToken is IERC20 {
+    /// @notice Mints tokens
+    /// @param amount The amount to mint
+    function mint(uint256 amount) external;
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import { IToken } from "./IToken.sol";
+import "solmate/tokens/Owned.sol";
+
+/// @title Token
+/// @notice A token
+contract Token is IToken, Owned {
+    /// @inheritdoc IToken
+    function mint(uint256 amount) external {}
+
+    /// @inheritdoc IERC20
+    function totalSupply() external view returns (uint256) {}
+
+    /// @inheritdoc IERC20
+    function balanceOf(address account) external view returns (uint256) {}
+
+    /// @inheritdoc Owned
+    function owner() external view override returns (address) {}
+
+    /// @inheritdoc IERC721
+    function approve(address to) external {}
+}
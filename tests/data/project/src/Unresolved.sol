@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import {IMissing} from "./IMissing.sol";
+import {Broken} from "./Broken.sol.txt";
+
+/// @title Unresolved
+/// @author natlint
+/// @notice A contract with imports that cannot be loaded
+contract Unresolved {}
@@ -2,6 +2,7 @@
 
 use natlint::config::Config;
 use natlint::linter::lint;
+use natlint::prelude::*;
 use std::fs;
 use std::path::Path;
 
@@ -13,7 +14,7 @@ fn test_contract() {
     let config = Config::default();
     let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
 
-    assert_eq!(violations.len(), 14);
+    assert_eq!(violations.len(), 15);
 
     assert_eq!(violations[0].0.rule_name, "MissingNotice");
     assert_eq!(violations[0].1, 6);
@@ -97,25 +98,33 @@ fn test_contract() {
         "Structs must document all parameters."
     );
 
-    // Function violations
-    assert_eq!(violations[11].0.rule_name, "MissingInheritdoc");
-    assert_eq!(violations[11].1, 22);
+    // Type violations
+    assert_eq!(violations[11].0.rule_name, "MissingNotice");
+    assert_eq!(violations[11].1, 20);
     assert_eq!(
         violations[11].0.rule_description,
-        "Public and override functions must have an inheritdoc comment."
+        "User-defined value types must have a notice comment."
     );
 
-    assert_eq!(violations[12].0.rule_name, "MissingNotice");
+    // Function violations
+    assert_eq!(violations[12].0.rule_name, "MissingInheritdoc");
     assert_eq!(violations[12].1, 22);
     assert_eq!(
         violations[12].0.rule_description,
-        "Functions must have a notice or an inheritdoc comment."
+        "Public and override functions must have an inheritdoc comment."
     );
 
-    assert_eq!(violations[13].0.rule_name, "MissingParams");
+    assert_eq!(violations[13].0.rule_name, "MissingNotice");
     assert_eq!(violations[13].1, 22);
     assert_eq!(
         violations[13].0.rule_description,
+        "Functions must have a notice or an inheritdoc comment."
+    );
+
+    assert_eq!(violations[14].0.rule_name, "MissingParams");
+    assert_eq!(violations[14].1, 22);
+    assert_eq!(
+        violations[14].0.rule_description,
         "Functions must have their parameters documented or have an inheritdoc comment."
     );
 }
@@ -140,6 +149,50 @@ fn test_interface() {
     assert_eq!(violations[3].1, 5);
 }
 
+#[test]
+fn test_interface_with_kind_config() {
+    let file_path = Path::new("tests/data/TestInterface.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::from_file(Path::new("tests/data/kind_config.toml"))
+        .expect("Failed to read config file");
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    assert_eq!(violations.len(), 4);
+
+    assert_eq!(violations[0].0.rule_name, "MissingAuthor");
+    assert_eq!(violations[0].1, 4);
+    assert_eq!(violations[1].0.rule_name, "MissingNotice");
+    assert_eq!(violations[1].1, 4);
+    assert_eq!(violations[2].0.rule_name, "MissingNotice");
+    assert_eq!(violations[2].1, 5);
+    assert_eq!(violations[3].0.rule_name, "MissingParams");
+    assert_eq!(violations[3].1, 5);
+}
+
+#[test]
+fn test_contract_with_kind_config() {
+    let file_path = Path::new("tests/data/TestInterface.sol");
+    let content = fs::read_to_string(file_path)
+        .expect("Failed to read test file")
+        .replace("interface", "abstract contract");
+
+    let config = Config::from_file(Path::new("tests/data/kind_config.toml"))
+        .expect("Failed to read config file");
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    // Top-level tables apply, so the missing parameter docs are not reported
+    assert_eq!(violations.len(), 5);
+
+    assert_eq!(violations[0].0.rule_name, "MissingAuthor");
+    assert_eq!(violations[1].0.rule_name, "MissingNotice");
+    assert_eq!(violations[2].0.rule_name, "MissingTitle");
+    assert_eq!(violations[3].0.rule_name, "MissingInheritdoc");
+    assert_eq!(violations[3].1, 5);
+    assert_eq!(violations[4].0.rule_name, "MissingNotice");
+    assert_eq!(violations[4].1, 5);
+}
+
 #[test]
 fn test_msgs() {
     let file_path = Path::new("tests/data/TestMsgs.sol");
@@ -168,7 +221,7 @@ fn test_contract_with_disable() {
     let config = Config::default();
     let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
 
-    assert_eq!(violations.len(), 8);
+    assert_eq!(violations.len(), 9);
 
     assert_eq!(violations[0].0.rule_name, "MissingNotice");
     assert_eq!(violations[0].1, 6);
@@ -232,5 +285,366 @@ fn test_contract_with_disable() {
         "Structs must have a notice comment."
     );
 
+    // Type violations
+    assert_eq!(violations[8].0.rule_name, "MissingNotice");
+    assert_eq!(violations[8].1, 22);
+    assert_eq!(
+        violations[8].0.rule_description,
+        "User-defined value types must have a notice comment."
+    );
+
     // No Function violations
 }
+
+#[test]
+fn test_modifier() {
+    let file_path = Path::new("tests/data/TestModifier.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::default();
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    assert_eq!(violations.len(), 3);
+
+    assert_eq!(violations[0].0.rule_name, "MissingNotice");
+    assert_eq!(violations[0].1, 10);
+    assert_eq!(
+        violations[0].0.rule_description,
+        "Modifiers must have a notice or an inheritdoc comment."
+    );
+
+    assert_eq!(violations[1].0.rule_name, "MissingParams");
+    assert_eq!(violations[1].1, 10);
+    assert_eq!(
+        violations[1].0.rule_description,
+        "Modifiers must have their parameters documented or have an inheritdoc comment."
+    );
+
+    assert_eq!(violations[2].0.rule_name, "NoAuthor");
+    assert_eq!(violations[2].1, 13);
+    assert_eq!(
+        violations[2].0.rule_description,
+        "Modifiers must not have an author comment."
+    );
+}
+
+#[test]
+fn test_constructor() {
+    let file_path = Path::new("tests/data/TestConstructor.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::default();
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    assert_eq!(violations.len(), 3);
+
+    assert_eq!(violations[0].0.rule_name, "MissingParams");
+    assert_eq!(violations[0].1, 8);
+    assert_eq!(
+        violations[0].0.rule_description,
+        "Constructors must have their parameters documented."
+    );
+
+    assert_eq!(violations[1].0.rule_name, "MissingNotice");
+    assert_eq!(violations[1].1, 10);
+    assert_eq!(
+        violations[1].0.rule_description,
+        "Receive and fallback functions must have a notice or an inheritdoc comment."
+    );
+
+    assert_eq!(violations[2].0.rule_name, "MissingReturn");
+    assert_eq!(violations[2].1, 14);
+    assert_eq!(
+        violations[2].0.rule_description,
+        "Receive and fallback functions must have their return variables documented or have an inheritdoc comment."
+    );
+}
+
+#[test]
+fn test_visibility() {
+    let file_path = Path::new("tests/data/TestVisibility.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::default();
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    assert_eq!(violations.len(), 6);
+}
+
+#[test]
+fn test_visibility_with_filter_config() {
+    let file_path = Path::new("tests/data/TestVisibility.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::from_file(Path::new("tests/data/filter_config.toml"))
+        .expect("Failed to read config file");
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    // Internal and private functions only need a notice
+    assert_eq!(violations.len(), 3);
+
+    assert_eq!(violations[0].0.rule_name, "MissingParams");
+    assert_eq!(violations[0].1, 8);
+    assert_eq!(violations[1].0.rule_name, "MissingReturn");
+    assert_eq!(violations[1].1, 8);
+    assert_eq!(violations[2].0.rule_name, "MissingNotice");
+    assert_eq!(violations[2].1, 13);
+}
+
+#[test]
+fn test_inheritdoc() {
+    let file_path = Path::new("tests/data/TestInheritdoc.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::default();
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    assert_eq!(violations.len(), 2);
+
+    assert_eq!(violations[0].0.rule_name, "InvalidInheritdoc");
+    assert_eq!(violations[0].1, 19);
+    assert_eq!(
+        violations[0].0.rule_description,
+        "Function inheritdoc comments must reference a base of the contract."
+    );
+    assert_eq!(
+        violations[0].0.error.to_string(),
+        "`IBar` is not a base of the contract"
+    );
+
+    assert_eq!(violations[1].0.rule_name, "UnmatchedInheritdoc");
+    assert_eq!(violations[1].1, 22);
+    assert_eq!(
+        violations[1].0.error.to_string(),
+        "`IFoo` does not declare this member"
+    );
+}
+
+#[test]
+fn test_tags() {
+    let file_path = Path::new("tests/data/TestTags.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    let config = Config::default();
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    assert_eq!(violations.len(), 6);
+
+    // The misspelled notice is ignored, so the notice is missing too
+    assert_eq!(violations[0].0.rule_name, "MissingNotice");
+    assert_eq!(violations[0].1, 8);
+
+    assert_eq!(violations[1].0.rule_name, "UnknownTag");
+    assert_eq!(violations[1].1, 7);
+    assert_eq!(
+        violations[1].0.error.to_string(),
+        "Unknown tag `@notic`, did you mean `@notice`?"
+    );
+
+    assert_eq!(violations[2].0.rule_name, "MalformedTag");
+    assert_eq!(violations[2].1, 11);
+    assert_eq!(
+        violations[2].0.error.to_string(),
+        "Malformed tag `@`, did you mean `@dev`?"
+    );
+
+    // A parameter comment without a description doesn't document the parameter
+    assert_eq!(violations[3].0.rule_name, "MissingParams");
+    assert_eq!(violations[3].1, 16);
+
+    assert_eq!(violations[4].0.rule_name, "EmptyTag");
+    assert_eq!(violations[4].1, 16);
+    assert_eq!(
+        violations[4].0.error.to_string(),
+        "Empty Param comment for `amount`"
+    );
+
+    // File rules are checked after the items
+    assert_eq!(violations[5].0.rule_name, "OrphanDocComment");
+    assert_eq!(violations[5].1, 18);
+}
+
+/// A plugin rule, requiring a dev comment on payable functions.
+struct MissingPayableDev;
+
+impl Rule for MissingPayableDev {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "MissingPayableDev";
+    const DESCRIPTION: &'static str = "Payable functions must have a dev comment.";
+
+    fn check(
+        _: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        let payable = func
+            .attributes
+            .iter()
+            .any(|attr| matches!(attr, FunctionAttribute::Mutability(Mutability::Payable(_))));
+        (payable && comments.include_tag(CommentTag::Dev).is_empty()).then(|| {
+            Violation::new(
+                Self::NAME,
+                Self::DESCRIPTION,
+                ViolationError::MissingComment(CommentTag::Dev),
+                func.loc,
+            )
+        })
+    }
+}
+
+/// A plugin rule, forbidding TODOs in notice comments.
+struct NoTodo;
+
+impl Rule for NoTodo {
+    type Target = FunctionDefinition;
+    const NAME: &'static str = "NoTodo";
+    const DESCRIPTION: &'static str = "Notice comments must not contain a TODO.";
+
+    fn check(
+        _: Option<&ParseItem>,
+        func: &FunctionDefinition,
+        comments: &CommentsRef,
+    ) -> Option<Violation> {
+        comments
+            .include_tag(CommentTag::Notice)
+            .iter()
+            .any(|comment| comment.value.contains("TODO"))
+            .then(|| {
+                Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    ViolationError::CommentNotAllowed(CommentTag::Notice),
+                    func.loc,
+                )
+            })
+    }
+}
+
+#[test]
+fn test_plugin_rules() {
+    let content = r"
+        contract Vault {
+            /// @notice TODO
+            function deposit() external payable {}
+
+            /// @notice Deposits for another account
+            function depositFor(address account) public payable {}
+        }
+    ";
+    let plugin_rules = |linter: LinterBuilder| {
+        linter
+            .disabled_rule("missing_payable_dev", MissingPayableDev)
+            .rule("no_todo", NoTodo)
+    };
+    let plugin_violations = |linter: Linter| {
+        linter
+            .lint(content)
+            .expect("Failed to process file")
+            .into_iter()
+            .filter(|(violation, _)| {
+                violation.rule_name.contains("Payable") || violation.rule_name == "NoTodo"
+            })
+            .map(|(violation, line)| (violation.rule_name, line))
+            .collect::<Vec<_>>()
+    };
+
+    // Plugin rules are enabled according to their registered default
+    let linter = plugin_rules(Linter::builder()).build();
+    assert_eq!(plugin_violations(linter), [("NoTodo", 4)]);
+
+    // The configuration overrides the default, and can filter the plugin rules
+    let config = Config::from_file(Path::new("tests/data/plugin_config.toml"))
+        .expect("Failed to read config file");
+    let linter = plugin_rules(Linter::builder().config(config)).build();
+    assert_eq!(plugin_violations(linter), [("MissingPayableDev", 4)]);
+
+    // The default configuration lists the plugin rules
+    let config = plugin_rules(Linter::builder()).default_config();
+    assert!(!config.plugin_rules.enabled["missing_payable_dev"]);
+    assert!(config.plugin_rules.enabled["no_todo"]);
+}
+
+/// A file rule, forbidding TODOs in any comment.
+struct NoTodoComment;
+
+impl FileRule for NoTodoComment {
+    const NAME: &'static str = "NoTodoComment";
+    const DESCRIPTION: &'static str = "Comments must not contain a TODO.";
+
+    fn check(_: &RuleContext, file: &SourceFile) -> Vec<Violation> {
+        file.comments
+            .iter()
+            .filter(|comment| comment.value().contains("TODO"))
+            .map(|comment| {
+                Violation::new(
+                    Self::NAME,
+                    Self::DESCRIPTION,
+                    ViolationError::parse_error("TODO"),
+                    comment.loc(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_file_rules() {
+    let content = r"
+        /// @title Vault
+        /// @author Natlint
+        contract Vault {
+            /// @notice Deposits
+            function deposit() external {
+                // TODO: implement
+            }
+
+            // natlint-disable-next-line NoTodoComment
+            // TODO: withdraw
+        }
+        // TODO: more vaults
+    ";
+    let rules: Vec<Box<dyn DynRule>> = vec![Box::new(FileRuleAdapter::<NoTodoComment>::new())];
+
+    // Comments inside function bodies and outside items are seen, and can be disabled
+    let violations = lint(content, &rules).expect("Failed to process file");
+    assert_eq!(
+        violations
+            .iter()
+            .map(|(violation, line)| (violation.rule_name, *line))
+            .collect::<Vec<_>>(),
+        [("NoTodoComment", 7), ("NoTodoComment", 13)]
+    );
+}
+
+#[test]
+fn test_return_comments() {
+    let content = r"
+        /// @title Vault
+        /// @notice A vault
+        contract Vault {
+            /// @notice Redeems shares
+            /// @return assets The assets
+            /// @return shares The shares
+            function redeem() internal returns (uint256 shares, uint256 assets) {}
+
+            /// @notice Previews a redemption
+            /// @return The shares
+            function preview() internal returns (uint256 shares) {}
+        }
+    ";
+
+    // Each swapped comment is reported, and an undocumented name only once
+    let config = Config::default();
+    let violations = lint(content, &config.rules()).expect("Failed to process file");
+    assert_eq!(
+        violations
+            .iter()
+            .map(|(violation, line)| (violation.rule_name, *line))
+            .collect::<Vec<_>>(),
+        [
+            ("MismatchedReturn", 6),
+            ("MismatchedReturn", 7),
+            ("MissingReturn", 12)
+        ]
+    );
+}
@@ -0,0 +1,244 @@
+//! Project test suite
+
+use natlint::config::Config;
+use natlint::linter::{lint, lint_project, LintOptions};
+use natlint::project::Project;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ROOT: &str = "tests/data/project";
+
+fn load_project() -> Project {
+    let targets = ["src/IToken.sol", "src/Token.sol"].map(|file| Path::new(ROOT).join(file));
+    Project::load(Path::new(ROOT), targets)
+}
+
+/// Returns the canonical path of a file of the test project, as the project stores it.
+fn path(file: &str) -> PathBuf {
+    fs::canonicalize(Path::new(ROOT).join(file)).expect("Failed to canonicalize path")
+}
+
+#[test]
+fn test_load_project() {
+    let project = load_project();
+
+    let targets = project.targets().map(|file| &file.path).collect::<Vec<_>>();
+    assert_eq!(targets, [&path("src/IToken.sol"), &path("src/Token.sol")]);
+
+    // Imports are resolved through remappings.txt and the lib directory
+    assert!(project
+        .file(Path::new(
+            "tests/data/project/lib/oz/contracts/token/IERC20.sol"
+        ))
+        .is_some());
+    assert!(project
+        .file(Path::new(
+            "tests/data/project/lib/solmate/src/tokens/Owned.sol"
+        ))
+        .is_some());
+    assert!(project.skipped_imports().is_empty());
+    assert!(project.skipped_files().is_empty());
+    assert!(project.remapping_errors().is_empty());
+}
+
+#[test]
+fn test_load_project_with_absolute_and_relative_targets() {
+    let absolute = path("src/Token.sol");
+    let relative = Path::new(ROOT).join("src/../src/Token.sol");
+    let project = Project::load(Path::new(ROOT), [absolute.clone(), relative.clone()]);
+
+    let targets = project.targets().map(|file| &file.path).collect::<Vec<_>>();
+    assert_eq!(targets, [&absolute]);
+    assert!(project.file(&relative).is_some());
+}
+
+#[test]
+fn test_load_project_with_skipped_files() {
+    let targets = ["src/Broken.sol.txt", "src/Missing.sol", "src/Token.sol"]
+        .map(|file| Path::new(ROOT).join(file));
+    let project = Project::load(Path::new(ROOT), targets);
+
+    // The files that cannot be read or parsed don't stop the others from being loaded
+    let skipped = project
+        .skipped_files()
+        .iter()
+        .map(|skipped| skipped.path.file_name().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(skipped, ["Broken.sol.txt", "Missing.sol"]);
+
+    let targets = project.targets().map(|file| &file.path).collect::<Vec<_>>();
+    assert_eq!(targets, [&path("src/Token.sol")]);
+}
+
+#[test]
+fn test_load_project_with_invalid_remappings() {
+    let root = Path::new("tests/data/broken_remappings");
+    let project = Project::load(root, [root.join("src/Token.sol")]);
+
+    let errors = project
+        .remapping_errors()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0],
+        "Invalid remapping `not-a-remapping`: expected `prefix=target`"
+    );
+    assert!(errors[1].starts_with("Failed to parse foundry.toml"));
+
+    // The valid remappings are still applied
+    assert_eq!(project.targets().count(), 1);
+    assert!(project.skipped_imports().is_empty());
+}
+
+#[test]
+fn test_load_project_with_skipped_imports() {
+    let project = Project::load(
+        Path::new(ROOT),
+        [Path::new(ROOT).join("src/Unresolved.sol")],
+    );
+
+    let skipped = project
+        .skipped_imports()
+        .iter()
+        .map(|skipped| (skipped.import.as_str(), skipped.reason.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0], ("./IMissing.sol", "file not found"));
+    assert_eq!(skipped[1].0, "./Broken.sol.txt");
+    assert_eq!(
+        project.skipped_imports()[0].from,
+        path("src/Unresolved.sol")
+    );
+}
+
+#[test]
+fn test_resolve_contract() {
+    let project = load_project();
+    let token_path = &path("src/Token.sol");
+
+    let token = project
+        .resolve_contract(token_path, "Token")
+        .expect("Token should be declared");
+    assert_eq!(token.path, token_path);
+
+    let owned = project
+        .resolve_contract(token_path, "Owned")
+        .expect("Owned should be imported");
+    assert_eq!(owned.path, path("lib/solmate/src/tokens/Owned.sol"));
+
+    // IERC20 is only imported by IToken.sol
+    assert!(project.resolve_contract(token_path, "IERC20").is_none());
+
+    let ancestors = project.ancestors(&token);
+    let names = ancestors
+        .iter()
+        .map(|ancestor| ancestor.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["IToken", "Owned", "IERC20"]);
+    assert!(ancestors.iter().all(|ancestor| ancestor.contract.is_some()));
+
+    assert_eq!(project.inherits_from(&token, "IERC20"), Some(true));
+    assert_eq!(project.inherits_from(&token, "IERC721"), Some(false));
+}
+
+#[test]
+fn test_lint_project() {
+    let project = load_project();
+
+    let config = Config::default();
+    let violations = lint_project(&project, &config.rules(), config.lint_options());
+
+    assert_eq!(violations.len(), 2);
+    assert!(violations[0].1.is_empty());
+
+    let (file, violations) = &violations[1];
+    assert_eq!(file, &path("src/Token.sol"));
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].0.rule_name, "InvalidInheritdoc");
+    assert_eq!(violations[0].1, 23);
+    assert_eq!(
+        violations[0].0.error.to_string(),
+        "`IERC721` is not a base of the contract"
+    );
+}
+
+#[test]
+fn test_lint_project_effective_docs() {
+    let project = load_project();
+
+    let config = Config::default();
+    let options = LintOptions {
+        effective_docs: true,
+    };
+    let violations = lint_project(&project, &config.rules(), options);
+
+    // The incomplete docs inherited by `Token.balanceOf` are reported where they are declared
+    let violations = violations
+        .iter()
+        .map(|(file, violations)| {
+            let violations = violations
+                .iter()
+                .map(|(violation, line)| (violation.rule_name, *line))
+                .collect::<Vec<_>>();
+            (file.clone(), violations)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        [
+            (
+                path("lib/oz/contracts/token/IERC20.sol"),
+                vec![("MissingParams", 12), ("MissingReturn", 12)]
+            ),
+            (path("src/IToken.sol"), vec![]),
+            (path("src/Token.sol"), vec![("InvalidInheritdoc", 23)]),
+        ]
+    );
+}
+
+#[test]
+fn test_lint_project_effective_docs_own_tags() {
+    let targets = [Path::new(ROOT).join("src/Child.sol")];
+    let project = Project::load(Path::new(ROOT), targets);
+
+    let config = Config::default();
+    let options = LintOptions {
+        effective_docs: true,
+    };
+    let violations = lint_project(&project, &config.rules(), options);
+
+    // The `@author` of `Child.mint` is its own, so the inherited docs of `IToken` are not blamed
+    assert_eq!(violations.len(), 1);
+    let (file, violations) = &violations[0];
+    assert_eq!(file, &path("src/Child.sol"));
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].0.rule_name, "NoAuthor");
+    assert_eq!(violations[0].1, 10);
+}
+
+#[test]
+fn test_lint_without_project() {
+    let file_path = Path::new(ROOT).join("src/Token.sol");
+    let content = fs::read_to_string(file_path).expect("Failed to read test file");
+
+    // Without the project, only the direct bases are known, so the inherited bases are reported,
+    // and the functions documented from an unresolved direct base must be overrides
+    let config = Config::default();
+    let violations: Vec<_> = lint(&content, &config.rules()).expect("Failed to process file");
+
+    let violations = violations
+        .iter()
+        .map(|(violation, line)| (violation.rule_name, *line))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        [
+            ("UnmatchedInheritdoc", 11),
+            ("InvalidInheritdoc", 14),
+            ("InvalidInheritdoc", 17),
+            ("InvalidInheritdoc", 23),
+        ]
+    );
+}